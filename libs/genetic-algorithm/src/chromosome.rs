@@ -2,23 +2,58 @@ use std::iter::FromIterator;
 use std::ops::Index;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Chromosome {
     pub genes: Vec<f32>,
+    // Strategy parameters (e.g. mutation step sizes) that evolve alongside the
+    // genes, but aren't part of the solution itself; empty unless a mutation
+    // method makes use of them.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub strategy: Vec<f32>,
 }
 
 impl Chromosome {
     pub fn new(genes: Vec<f32>) -> Self {
-        Chromosome { genes }
+        Chromosome {
+            genes,
+            strategy: Vec::new(),
+        }
+    }
+
+    pub fn with_strategy(mut self, strategy: Vec<f32>) -> Self {
+        self.strategy = strategy;
+        self
     }
 
     pub fn len(&self) -> usize {
         self.genes.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.genes.is_empty()
+    }
+
     pub fn genes(&self) -> &Vec<f32> {
         &self.genes
     }
 
+    pub fn strategy(&self) -> &Vec<f32> {
+        &self.strategy
+    }
+
+    // Crossover methods only deal with genes - strategy parameters are
+    // recombined by averaging the parents' ones.
+    pub(crate) fn inherit_strategy(&mut self, parent_a: &Chromosome, parent_b: &Chromosome) {
+        if self.strategy.is_empty() && parent_a.strategy.len() == parent_b.strategy.len() {
+            self.strategy = parent_a
+                .strategy
+                .iter()
+                .zip(&parent_b.strategy)
+                .map(|(a, b)| (a + b) / 2.0)
+                .collect();
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &f32> {
         self.genes.iter()
     }
@@ -38,9 +73,7 @@ impl Index<usize> for Chromosome {
 
 impl FromIterator<f32> for Chromosome {
     fn from_iter<T: IntoIterator<Item = f32>>(iter: T) -> Self {
-        Self {
-            genes: iter.into_iter().collect(),
-        }
+        Self::new(iter.into_iter().collect())
     }
 }
 
@@ -48,6 +81,7 @@ impl FromIterator<f32> for Chromosome {
 impl PartialEq for Chromosome {
     fn eq(&self, other: &Self) -> bool {
         approx::relative_eq!(self.genes.as_slice(), other.genes.as_slice(),)
+            && approx::relative_eq!(self.strategy.as_slice(), other.strategy.as_slice(),)
     }
 }
 
@@ -56,9 +90,7 @@ mod tests {
     use super::*;
 
     fn chromosome() -> Chromosome {
-        Chromosome {
-            genes: vec![3.0, 1.0, 2.0],
-        }
+        Chromosome::new(vec![3.0, 1.0, 2.0])
     }
 
     mod new {
@@ -74,6 +106,19 @@ mod tests {
         }
     }
 
+    mod with_strategy {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let chromosome = chromosome().with_strategy(vec![0.5]);
+
+            assert_eq!(chromosome.genes(), &vec![3.0, 1.0, 2.0]);
+            assert_eq!(chromosome.strategy(), &vec![0.5]);
+            assert!(super::chromosome().strategy().is_empty());
+        }
+    }
+
     mod len {
         use super::*;
 
@@ -142,4 +187,20 @@ mod tests {
             assert_eq!(chromosome[2], 2.0);
         }
     }
+
+    #[cfg(feature = "serde")]
+    mod serialization {
+        use super::*;
+
+        #[test]
+        fn test() {
+            let chromosome = Chromosome::new(vec![3.0, 1.0]).with_strategy(vec![0.5, 0.25]);
+
+            let json = serde_json::to_string(&chromosome).unwrap();
+            assert_eq!(json, r#"{"genes":[3.0,1.0],"strategy":[0.5,0.25]}"#);
+
+            let actual: Chromosome = serde_json::from_str(&json).unwrap();
+            assert_eq!(actual, chromosome);
+        }
+    }
 }
@@ -0,0 +1,293 @@
+use crate::*;
+use nalgebra::{DMatrix, DVector};
+use rand_distr::StandardNormal;
+use std::sync::Mutex;
+
+// Covariance matrix adaptation evolution strategy (CMA-ES): chromosomes are
+// sampled from a multivariate normal distribution, whose mean, step size and
+// covariance are then updated towards the fittest half of the samples.
+//
+// Like `DifferentialEvolution`, `evolve()` takes the evaluated samples and
+// returns the next ones to evaluate; the initial population only seeds the
+// distribution's mean. Covariance is adapted in full, so every generation
+// costs O(n^3) in the number of genes - fine for small networks.
+#[derive(Debug)]
+pub struct CmaEs {
+    initial_sigma: f64,
+    state: Mutex<Option<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    mean: DVector<f64>,
+    sigma: f64,
+    covariance: DMatrix<f64>,
+    // Eigendecomposition of the covariance: C = B * diag(D^2) * B^T.
+    b: DMatrix<f64>,
+    d: DVector<f64>,
+    // Evolution paths of sigma and of the covariance.
+    p_sigma: DVector<f64>,
+    p_c: DVector<f64>,
+    generation: usize,
+}
+
+impl CmaEs {
+    // `sigma` is the initial step size, i.e. roughly how far from the
+    // initial mean the optimum is expected to be, per gene.
+    pub fn new(sigma: f32) -> Self {
+        assert!(sigma > 0.0);
+
+        Self {
+            initial_sigma: sigma as _,
+            state: Mutex::new(None),
+        }
+    }
+
+    pub fn mean(&self) -> Option<Chromosome> {
+        self.state
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|state| to_chromosome(&state.mean))
+    }
+
+    pub fn sigma(&self) -> Option<f32> {
+        self.state
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|state| state.sigma as _)
+    }
+
+    pub fn evolve<I>(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics)
+    where
+        I: Individual,
+    {
+        assert!(population.len() >= 2);
+
+        let stats = Statistics::new(population);
+        let dimensions = population[0].to_chromosome().len();
+
+        assert!(dimensions > 0);
+        assert!(population
+            .iter()
+            .all(|individual| individual.to_chromosome().len() == dimensions));
+
+        let mut state = self.state.lock().unwrap();
+
+        match state.as_mut() {
+            Some(state) if state.mean.len() == dimensions => state.update(population),
+            _ => *state = Some(State::new(population, self.initial_sigma)),
+        }
+
+        let state = state.as_ref().unwrap();
+
+        let samples = (0..population.len())
+            .map(|_| I::from_chromosome(to_chromosome(&state.sample(rng))))
+            .collect();
+
+        (samples, stats)
+    }
+}
+
+impl<I> Optimizer<I> for CmaEs
+where
+    I: Individual,
+{
+    fn evolve(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics) {
+        CmaEs::evolve(self, rng, population)
+    }
+}
+
+impl State {
+    fn new<I>(population: &[I], sigma: f64) -> Self
+    where
+        I: Individual,
+    {
+        let dimensions = population[0].to_chromosome().len();
+
+        let mean = population
+            .iter()
+            .map(|individual| to_vector(individual.to_chromosome()))
+            .fold(DVector::zeros(dimensions), |sum, x| sum + x)
+            / population.len() as f64;
+
+        Self {
+            mean,
+            sigma,
+            covariance: DMatrix::identity(dimensions, dimensions),
+            b: DMatrix::identity(dimensions, dimensions),
+            d: DVector::repeat(dimensions, 1.0),
+            p_sigma: DVector::zeros(dimensions),
+            p_c: DVector::zeros(dimensions),
+            generation: 0,
+        }
+    }
+
+    fn sample(&self, rng: &mut dyn RngCore) -> DVector<f64> {
+        let z = DVector::from_fn(self.mean.len(), |_, _| rng.sample(StandardNormal));
+
+        &self.mean + self.sigma * (&self.b * self.d.component_mul(&z))
+    }
+
+    // Moves the distribution towards the fittest half of the population,
+    // following Hansen's "The CMA Evolution Strategy: A Tutorial".
+    fn update<I>(&mut self, population: &[I])
+    where
+        I: Individual,
+    {
+        let n = self.mean.len() as f64;
+        let mu = population.len() / 2;
+
+        let mut ranked: Vec<&I> = population.iter().collect();
+        ranked.sort_by(|a, b| b.fitness().total_cmp(&a.fitness()));
+
+        let weights: Vec<f64> = (1..=mu)
+            .map(|i| (mu as f64 + 0.5).ln() - (i as f64).ln())
+            .collect();
+        let weights_sum: f64 = weights.iter().sum();
+        let weights: Vec<f64> = weights.iter().map(|w| w / weights_sum).collect();
+        let mu_eff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let c_sigma = (mu_eff + 2.0) / (n + mu_eff + 5.0);
+        let d_sigma = 1.0 + 2.0 * (((mu_eff - 1.0) / (n + 1.0)).sqrt() - 1.0).max(0.0) + c_sigma;
+        let c_c = (4.0 + mu_eff / n) / (n + 4.0 + 2.0 * mu_eff / n);
+        let c_1 = 2.0 / ((n + 1.3).powi(2) + mu_eff);
+        let c_mu =
+            (1.0 - c_1).min(2.0 * (mu_eff - 2.0 + 1.0 / mu_eff) / ((n + 2.0).powi(2) + mu_eff));
+        let chi_n = n.sqrt() * (1.0 - 1.0 / (4.0 * n) + 1.0 / (21.0 * n * n));
+
+        let ys: Vec<DVector<f64>> = ranked[..mu]
+            .iter()
+            .map(|individual| (to_vector(individual.to_chromosome()) - &self.mean) / self.sigma)
+            .collect();
+
+        let y_w = ys
+            .iter()
+            .zip(&weights)
+            .fold(DVector::zeros(self.mean.len()), |sum, (y, w)| sum + y * *w);
+
+        self.mean += self.sigma * &y_w;
+        self.generation += 1;
+
+        // C^(-1/2) * y_w
+        let whitened = &self.b * (self.b.tr_mul(&y_w)).component_div(&self.d);
+
+        self.p_sigma = (1.0 - c_sigma) * &self.p_sigma
+            + (c_sigma * (2.0 - c_sigma) * mu_eff).sqrt() * whitened;
+
+        let p_sigma_norm = self.p_sigma.norm();
+
+        let h_sigma = p_sigma_norm
+            / (1.0 - (1.0 - c_sigma).powi(2 * self.generation as i32)).sqrt()
+            < (1.4 + 2.0 / (n + 1.0)) * chi_n;
+        let h_sigma = if h_sigma { 1.0 } else { 0.0 };
+
+        self.p_c = (1.0 - c_c) * &self.p_c + h_sigma * (c_c * (2.0 - c_c) * mu_eff).sqrt() * &y_w;
+
+        let rank_one = &self.p_c * self.p_c.transpose()
+            + (1.0 - h_sigma) * c_c * (2.0 - c_c) * &self.covariance;
+
+        let rank_mu = ys.iter().zip(&weights).fold(
+            DMatrix::zeros(self.mean.len(), self.mean.len()),
+            |sum, (y, w)| sum + *w * y * y.transpose(),
+        );
+
+        self.covariance = (1.0 - c_1 - c_mu) * &self.covariance + c_1 * rank_one + c_mu * rank_mu;
+        self.sigma *= ((c_sigma / d_sigma) * (p_sigma_norm / chi_n - 1.0)).exp();
+
+        // Enforce symmetry, which rounding errors slowly break.
+        self.covariance = (&self.covariance + self.covariance.transpose()) / 2.0;
+
+        let eigen = self.covariance.clone().symmetric_eigen();
+
+        self.b = eigen.eigenvectors;
+        self.d = eigen
+            .eigenvalues
+            .map(|value| value.max(f64::EPSILON).sqrt());
+    }
+}
+
+fn to_vector(chromosome: &Chromosome) -> DVector<f64> {
+    DVector::from_iterator(chromosome.len(), chromosome.iter().map(|&gene| gene as f64))
+}
+
+fn to_chromosome(vector: &DVector<f64>) -> Chromosome {
+    Chromosome::new(vector.iter().map(|&gene| gene as f32).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    // Negated squared distance from [1.0, 2.0, ...], so the fitness peaks at
+    // 0.0 there.
+    struct SphereIndividual {
+        chromosome: Chromosome,
+    }
+
+    impl Individual for SphereIndividual {
+        fn from_chromosome(chromosome: Chromosome) -> Self {
+            Self { chromosome }
+        }
+
+        fn fitness(&self) -> f32 {
+            -self
+                .chromosome
+                .iter()
+                .enumerate()
+                .map(|(i, gene)| (gene - (i + 1) as f32).powi(2))
+                .sum::<f32>()
+        }
+
+        fn to_chromosome(&self) -> &Chromosome {
+            &self.chromosome
+        }
+    }
+
+    fn population() -> Vec<SphereIndividual> {
+        (0..10)
+            .map(|_| SphereIndividual::from_chromosome(Chromosome::new(vec![0.0; 4])))
+            .collect()
+    }
+
+    #[test]
+    fn test_converges() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let cma_es = CmaEs::new(0.5);
+
+        let mut population = population();
+
+        for _ in 0..150 {
+            population = cma_es.evolve(&mut rng, &population).0;
+        }
+
+        let mean = cma_es.mean().unwrap();
+
+        for (i, gene) in mean.iter().enumerate() {
+            assert_relative_eq!(*gene, (i + 1) as f32, epsilon = 1e-3);
+        }
+
+        assert!(cma_es.sigma().unwrap() < 0.01);
+    }
+
+    #[test]
+    fn test_initial_mean() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let cma_es = CmaEs::new(1.0);
+
+        let population = vec![
+            SphereIndividual::from_chromosome(Chromosome::new(vec![0.0, 2.0])),
+            SphereIndividual::from_chromosome(Chromosome::new(vec![2.0, 4.0])),
+        ];
+
+        let (samples, _) = cma_es.evolve(&mut rng, &population);
+
+        assert_eq!(samples.len(), 2);
+        assert_eq!(cma_es.mean().unwrap().genes(), &vec![1.0, 3.0]);
+        assert_eq!(cma_es.sigma(), Some(1.0));
+    }
+}
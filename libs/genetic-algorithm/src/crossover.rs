@@ -1,7 +1,10 @@
-pub use self::uniform::*;
+pub use self::{arithmetic::*, k_point::*, single_point::*, uniform::*};
 
 use crate::*;
 
+mod arithmetic;
+mod k_point;
+mod single_point;
 mod uniform;
 
 pub trait CrossoverMethod {
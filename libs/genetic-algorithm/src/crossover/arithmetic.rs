@@ -0,0 +1,96 @@
+use crate::*;
+
+// Blend crossover (BLX-alpha): every gene is drawn uniformly from the interval
+// spanned by both parents' genes, extended on each side by `alpha` times its
+// length.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "ArithmeticCrossoverRaw")
+)]
+pub struct ArithmeticCrossover {
+    alpha: f32,
+}
+
+impl ArithmeticCrossover {
+    pub fn new(alpha: f32) -> Self {
+        Self::try_new(alpha).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(alpha: f32) -> Result<Self, Error> {
+        check_parameter("blend alpha", alpha, alpha >= 0.0)?;
+
+        Ok(Self { alpha })
+    }
+}
+
+impl CrossoverMethod for ArithmeticCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert!(!parent_a.is_empty());
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| {
+                let extent = self.alpha * (a - b).abs();
+
+                rng.gen_range((a.min(b) - extent)..=(a.max(b) + extent))
+            })
+            .collect()
+    }
+}
+
+// Deserialized as-is, then checked by `try_new()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ArithmeticCrossoverRaw {
+    alpha: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ArithmeticCrossoverRaw> for ArithmeticCrossover {
+    type Error = Error;
+
+    fn try_from(raw: ArithmeticCrossoverRaw) -> Result<Self, Error> {
+        Self::try_new(raw.alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(alpha: f32) -> Chromosome {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a = Chromosome::new(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let parent_b = Chromosome::new(vec![-1.0, 2.0, 5.0, 4.0, 1.0]);
+
+        ArithmeticCrossover::new(alpha).crossover(&mut rng, &parent_a, &parent_b)
+    }
+
+    #[test]
+    fn test_zero_alpha() {
+        let child = actual(0.0);
+        let expected = vec![-0.6255188, 2.0, 4.818126, 4.0, 4.0477614];
+
+        assert_relative_eq!(child.genes().as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_nonzero_alpha() {
+        let child = actual(0.5);
+        let expected = vec![-1.2510376, 2.0, 5.6362524, 4.0, 5.095523];
+
+        assert_relative_eq!(child.genes().as_slice(), expected.as_slice());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::*;
+use rand::seq::index;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "KPointCrossoverRaw")
+)]
+pub struct KPointCrossover {
+    points: usize,
+}
+
+impl KPointCrossover {
+    // points: Number of cut points; the child switches parents at each one.
+    pub fn new(points: usize) -> Self {
+        Self::try_new(points).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(points: usize) -> Result<Self, Error> {
+        check_parameter("number of cut points", points as f32, points > 0)?;
+
+        Ok(Self { points })
+    }
+}
+
+impl CrossoverMethod for KPointCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        if parent_a.is_empty() {
+            return parent_a.clone();
+        }
+
+        // Cut points are picked from [1, len), so that no segment is empty;
+        // short chromosomes get cut at every point there is.
+        let cuts = parent_a.len() - 1;
+        let mut points = index::sample(rng, cuts, self.points.min(cuts)).into_vec();
+        points.sort_unstable();
+
+        let mut points = points.into_iter().map(|point| point + 1).peekable();
+        let mut from_a = true;
+
+        let mut child = Vec::with_capacity(parent_a.len());
+        for i in 0..parent_a.len() {
+            if points.next_if_eq(&i).is_some() {
+                from_a = !from_a;
+            }
+
+            child.push(if from_a { parent_a[i] } else { parent_b[i] });
+        }
+
+        Chromosome::new(child)
+    }
+}
+
+// Deserialized as-is, then checked by `try_new()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct KPointCrossoverRaw {
+    points: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<KPointCrossoverRaw> for KPointCrossover {
+    type Error = Error;
+
+    fn try_from(raw: KPointCrossoverRaw) -> Result<Self, Error> {
+        Self::try_new(raw.points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = (1..=1000).map(|n| n as f32).collect();
+        let parent_b: Chromosome = (1..=1000).map(|n| -n as f32).collect();
+
+        let child = KPointCrossover::new(3).crossover(&mut rng, &parent_a, &parent_b);
+
+        let mut points = Vec::new();
+        for i in 0..1000 {
+            assert_eq!(child[i].abs(), parent_a[i]);
+
+            if i > 0 && child[i].signum() != child[i - 1].signum() {
+                points.push(i);
+            }
+        }
+
+        assert!(child[0] > 0.0);
+        assert_eq!(points, vec![187, 836, 909]);
+    }
+
+    #[test]
+    fn test_more_points_than_genes() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a = Chromosome::new(vec![1.0, 2.0, 3.0]);
+        let parent_b = Chromosome::new(vec![-1.0, -2.0, -3.0]);
+
+        let child = KPointCrossover::new(5).crossover(&mut rng, &parent_a, &parent_b);
+        assert_eq!(child, Chromosome::new(vec![1.0, -2.0, 3.0]));
+
+        let parent_a = Chromosome::new(vec![1.0]);
+        let parent_b = Chromosome::new(vec![-1.0]);
+
+        let child = KPointCrossover::new(5).crossover(&mut rng, &parent_a, &parent_b);
+        assert_eq!(child, parent_a);
+    }
+}
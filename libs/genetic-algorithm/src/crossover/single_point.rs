@@ -0,0 +1,81 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SinglePointCrossover;
+
+impl SinglePointCrossover {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for SinglePointCrossover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CrossoverMethod for SinglePointCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        // There's nowhere to cut a chromosome shorter than two genes.
+        if parent_a.len() < 2 {
+            return parent_a.clone();
+        }
+
+        // Genes [0, point) come from parent_a, genes [point, len) from parent_b.
+        let point = rng.gen_range(1..parent_a.len());
+
+        parent_a
+            .iter()
+            .take(point)
+            .chain(parent_b.iter().skip(point))
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a: Chromosome = (1..=1000).map(|n| n as f32).collect();
+        let parent_b: Chromosome = (1..=1000).map(|n| -n as f32).collect();
+
+        let child = SinglePointCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        let point = child.iter().position(|gene| *gene < 0.0).unwrap();
+        assert_eq!(point, 837);
+
+        for i in 0..1000 {
+            if i < point {
+                assert_eq!(child[i], parent_a[i]);
+            } else {
+                assert_eq!(child[i], parent_b[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_single_gene() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let parent_a = Chromosome::new(vec![1.0]);
+        let parent_b = Chromosome::new(vec![-1.0]);
+
+        let child = SinglePointCrossover::new().crossover(&mut rng, &parent_a, &parent_b);
+
+        assert_eq!(child, parent_a);
+    }
+}
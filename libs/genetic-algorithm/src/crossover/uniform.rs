@@ -1,12 +1,36 @@
 use crate::*;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UniformCrossover;
 
 impl UniformCrossover {
     pub fn new() -> Self {
         Self
     }
+
+    pub fn try_crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Result<Chromosome, Error> {
+        check_lengths([parent_a, parent_b])?;
+
+        let child = parent_a
+            .iter()
+            .zip(parent_b.iter())
+            .map(|(&a, &b)| if rng.gen_bool(0.5) { a } else { b })
+            .collect();
+
+        Ok(Chromosome::new(child))
+    }
+}
+
+impl Default for UniformCrossover {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CrossoverMethod for UniformCrossover {
@@ -16,21 +40,8 @@ impl CrossoverMethod for UniformCrossover {
         parent_a: &Chromosome,
         parent_b: &Chromosome,
     ) -> Chromosome {
-        assert!(parent_a.len() != 0);
-        assert_eq!(parent_a.len(), parent_b.len());
-
-        let gene_cnt = parent_a.len();
-        let mut child: Vec<f32> = Vec::with_capacity(gene_cnt);
-
-        for i in 0..gene_cnt {
-            child.push(if rng.gen_bool(0.5) {
-                parent_a[i]
-            } else {
-                parent_b[i]
-            })
-        }
-
-        Chromosome::new(child)
+        self.try_crossover(rng, parent_a, parent_b)
+            .unwrap_or_else(|err| panic!("{}", err))
     }
 }
 
@@ -62,4 +73,31 @@ mod tests {
         assert_eq!(diff_a, 515);
         assert_eq!(diff_b, 485);
     }
+
+    #[test]
+    fn test_try_crossover() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let crossover = UniformCrossover::new();
+
+        assert_eq!(
+            crossover
+                .try_crossover(
+                    &mut rng,
+                    &Chromosome::new(vec![1.0, 2.0]),
+                    &Chromosome::new(vec![1.0]),
+                )
+                .unwrap_err(),
+            Error::MismatchedChromosomeLengths {
+                expected: 2,
+                actual: 1
+            }
+        );
+
+        assert_eq!(
+            crossover
+                .try_crossover(&mut rng, &Chromosome::new(vec![]), &Chromosome::new(vec![]))
+                .unwrap_err(),
+            Error::EmptyChromosome
+        );
+    }
 }
@@ -0,0 +1,264 @@
+use crate::*;
+use std::sync::Mutex;
+
+// Differential evolution: every member of the population (the "target") gets
+// a trial vector built by adding the weighted difference of two other members
+// to a base vector, and crossing the result with the target gene by gene; the
+// trial replaces its target if it's at least as fit.
+//
+// Since fitness is evaluated by the caller, `evolve()` returns the trials and
+// settles the replacements on the next call, once they're evaluated. The
+// population has to be passed back in the same order.
+#[derive(Debug)]
+pub struct DifferentialEvolution {
+    base: Base,
+    weight: f32,
+    crossover_rate: f32,
+    // Surviving members, along with their fitness.
+    targets: Mutex<Vec<(Chromosome, f32)>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Base {
+    Random,
+    Best,
+}
+
+impl DifferentialEvolution {
+    // DE/rand/1/bin - the base vector is a random member; explores well, but
+    // needs at least 4 individuals.
+    pub fn rand_1_bin(weight: f32, crossover_rate: f32) -> Self {
+        Self::new(Base::Random, weight, crossover_rate)
+    }
+
+    // DE/best/1/bin - the base vector is the best member; converges faster,
+    // but needs at least 3 individuals.
+    pub fn best_1_bin(weight: f32, crossover_rate: f32) -> Self {
+        Self::new(Base::Best, weight, crossover_rate)
+    }
+
+    fn new(base: Base, weight: f32, crossover_rate: f32) -> Self {
+        assert!(weight >= 0.0);
+        assert!((0.0..=1.0).contains(&crossover_rate));
+
+        Self {
+            base,
+            weight,
+            crossover_rate,
+            targets: Mutex::new(Vec::new()),
+        }
+    }
+
+    // Best surviving chromosome so far, together with its fitness.
+    pub fn best(&self) -> Option<(Chromosome, f32)> {
+        self.targets
+            .lock()
+            .unwrap()
+            .iter()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .cloned()
+    }
+
+    pub fn evolve<I>(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics)
+    where
+        I: Individual,
+    {
+        let min_len = match self.base {
+            Base::Random => 4,
+            Base::Best => 3,
+        };
+
+        assert!(population.len() >= min_len);
+
+        let stats = Statistics::new(population);
+        let mut targets = self.targets.lock().unwrap();
+
+        if targets.len() == population.len() {
+            for (target, trial) in targets.iter_mut().zip(population) {
+                if trial.fitness() >= target.1 {
+                    *target = (trial.to_chromosome().clone(), trial.fitness());
+                }
+            }
+        } else {
+            // The initial population, or one that wasn't bred by us.
+            *targets = population
+                .iter()
+                .map(|individual| (individual.to_chromosome().clone(), individual.fitness()))
+                .collect();
+        }
+
+        let best = (0..targets.len())
+            .max_by(|&a, &b| targets[a].1.total_cmp(&targets[b].1))
+            .unwrap();
+
+        let trials = (0..targets.len())
+            .map(|i| {
+                let [base, a, b] = match self.base {
+                    Base::Random => pick(rng, targets.len(), &[i]),
+                    Base::Best => {
+                        let [a, b] = pick(rng, targets.len(), &[i, best]);
+                        [best, a, b]
+                    }
+                };
+
+                I::from_chromosome(self.trial(
+                    rng,
+                    &targets[i].0,
+                    [&targets[base].0, &targets[a].0, &targets[b].0],
+                ))
+            })
+            .collect();
+
+        (trials, stats)
+    }
+
+    fn trial(
+        &self,
+        rng: &mut dyn RngCore,
+        target: &Chromosome,
+        [base, a, b]: [&Chromosome; 3],
+    ) -> Chromosome {
+        assert_eq!(target.len(), base.len());
+        assert_eq!(target.len(), a.len());
+        assert_eq!(target.len(), b.len());
+
+        // Ensures at least one gene comes from the mutant.
+        let forced = rng.gen_range(0..target.len());
+
+        let genes = (0..target.len())
+            .map(|j| {
+                if j == forced || rng.gen_bool(self.crossover_rate as _) {
+                    base[j] + self.weight * (a[j] - b[j])
+                } else {
+                    target[j]
+                }
+            })
+            .collect();
+
+        Chromosome::new(genes).with_strategy(target.strategy().clone())
+    }
+}
+
+impl<I> Optimizer<I> for DifferentialEvolution
+where
+    I: Individual,
+{
+    fn evolve(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics) {
+        DifferentialEvolution::evolve(self, rng, population)
+    }
+}
+
+// Picks `N` distinct indices from 0..len, none of them in `excluded`.
+fn pick<const N: usize>(rng: &mut dyn RngCore, len: usize, excluded: &[usize]) -> [usize; N] {
+    let mut picked = [0; N];
+
+    for i in 0..N {
+        picked[i] = loop {
+            let candidate = rng.gen_range(0..len);
+
+            if !excluded.contains(&candidate) && !picked[..i].contains(&candidate) {
+                break candidate;
+            }
+        };
+    }
+
+    picked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::with_genes(vec![0.0, 0.0, 0.0]),
+            TestIndividual::with_genes(vec![1.0, 1.0, 1.0]),
+            TestIndividual::with_genes(vec![1.0, 2.0, 1.0]),
+            TestIndividual::with_genes(vec![1.0, 2.0, 4.0]),
+            TestIndividual::with_genes(vec![-1.0, 0.0, 2.0]),
+        ]
+    }
+
+    #[test]
+    fn test_rand_1_bin() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let de = DifferentialEvolution::rand_1_bin(0.5, 0.9);
+
+        let mut population = population();
+
+        for _ in 0..10 {
+            population = de.evolve(&mut rng, &population).0;
+        }
+
+        let expected = vec![
+            TestIndividual::with_genes(vec![1.8476563, 2.9609375, 5.517578]),
+            TestIndividual::with_genes(vec![1.4121094, 3.0488281, 5.836914]),
+            TestIndividual::with_genes(vec![2.0859375, 2.9140625, 5.2753906]),
+            TestIndividual::with_genes(vec![1.703125, 2.953125, 5.359375]),
+            TestIndividual::with_genes(vec![1.9550781, 2.9902344, 5.5478516]),
+        ];
+
+        assert_eq!(population, expected);
+        assert_eq!(de.best().unwrap().1, 10.408203);
+    }
+
+    #[test]
+    fn test_best_1_bin() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let de = DifferentialEvolution::best_1_bin(0.5, 0.9);
+
+        let mut population = population();
+
+        for _ in 0..10 {
+            population = de.evolve(&mut rng, &population).0;
+        }
+
+        let expected = vec![
+            TestIndividual::with_genes(vec![2.1191406, 5.908203, 4.923828]),
+            TestIndividual::with_genes(vec![2.1699219, 5.7246094, 4.919922]),
+            TestIndividual::with_genes(vec![2.1289063, 5.484375, 4.919922]),
+            TestIndividual::with_genes(vec![2.1621094, 5.6679688, 4.9179688]),
+            TestIndividual::with_genes(vec![2.1289063, 5.4140625, 4.919922]),
+        ];
+
+        assert_eq!(population, expected);
+        assert_eq!(de.best().unwrap().1, 12.8828125);
+    }
+
+    #[test]
+    fn test_never_regresses() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let de = DifferentialEvolution::rand_1_bin(0.8, 0.5);
+
+        let mut population = population();
+        let mut best = f32::NEG_INFINITY;
+
+        for _ in 0..50 {
+            population = de.evolve(&mut rng, &population).0;
+
+            let fitness = de.best().unwrap().1;
+            assert!(fitness >= best);
+            best = fitness;
+        }
+
+        assert!(best > 7.0);
+    }
+
+    #[test]
+    fn test_trial_keeps_one_mutant_gene() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let de = DifferentialEvolution::rand_1_bin(1.0, 0.0);
+
+        let target = Chromosome::new(vec![0.0; 4]);
+        let base = Chromosome::new(vec![1.0; 4]);
+        let a = Chromosome::new(vec![2.0; 4]);
+        let b = Chromosome::new(vec![1.0; 4]);
+
+        let trial = de.trial(&mut rng, &target, [&base, &a, &b]);
+
+        assert_eq!(trial.iter().filter(|&&gene| gene == 2.0).count(), 1);
+        assert_eq!(trial.iter().filter(|&&gene| gene == 0.0).count(), 3);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::*;
+use std::fmt;
+
+// Measures of how spread out a population is in gene space; values shrinking
+// towards zero mean the population has converged.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiversityStatistics {
+    mean_pairwise_distance: f32,
+    mean_centroid_distance: f32,
+    gene_variances: Vec<f32>,
+}
+
+impl DiversityStatistics {
+    pub fn new<I>(population: &[I]) -> Self
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+
+        let chromosomes: Vec<&Chromosome> = population
+            .iter()
+            .map(|individual| individual.to_chromosome())
+            .collect();
+
+        let population_size = chromosomes.len();
+        let gene_cnt = chromosomes[0].len();
+
+        assert!(chromosomes
+            .iter()
+            .all(|chromosome| chromosome.len() == gene_cnt));
+
+        let mut centroid = vec![0.0; gene_cnt];
+        for chromosome in &chromosomes {
+            for (sum, gene) in centroid.iter_mut().zip(chromosome.iter()) {
+                *sum += gene;
+            }
+        }
+        for gene in centroid.iter_mut() {
+            *gene /= population_size as f32;
+        }
+
+        let mut gene_variances = vec![0.0; gene_cnt];
+        for chromosome in &chromosomes {
+            for ((variance, mean), gene) in gene_variances
+                .iter_mut()
+                .zip(&centroid)
+                .zip(chromosome.iter())
+            {
+                *variance += (gene - mean).powi(2);
+            }
+        }
+        for variance in gene_variances.iter_mut() {
+            *variance /= population_size as f32;
+        }
+
+        let mean_centroid_distance = chromosomes
+            .iter()
+            .map(|chromosome| distance(chromosome.genes(), &centroid))
+            .sum::<f32>()
+            / population_size as f32;
+
+        let mut sum_pairwise_distance = 0.0;
+        for i in 0..population_size {
+            for j in (i + 1)..population_size {
+                sum_pairwise_distance += distance(chromosomes[i].genes(), chromosomes[j].genes());
+            }
+        }
+
+        let pair_cnt = population_size * (population_size - 1) / 2;
+        let mean_pairwise_distance = if pair_cnt > 0 {
+            sum_pairwise_distance / pair_cnt as f32
+        } else {
+            0.0
+        };
+
+        Self {
+            mean_pairwise_distance,
+            mean_centroid_distance,
+            gene_variances,
+        }
+    }
+
+    // Average Euclidean distance between every two chromosomes.
+    pub fn mean_pairwise_distance(&self) -> f32 {
+        self.mean_pairwise_distance
+    }
+
+    // Average Euclidean distance between a chromosome and the population's
+    // centroid (the per-gene mean).
+    pub fn mean_centroid_distance(&self) -> f32 {
+        self.mean_centroid_distance
+    }
+
+    pub fn gene_variances(&self) -> &[f32] {
+        &self.gene_variances
+    }
+
+    pub fn avg_gene_variance(&self) -> f32 {
+        if self.gene_variances.is_empty() {
+            return 0.0;
+        }
+
+        self.gene_variances.iter().sum::<f32>() / self.gene_variances.len() as f32
+    }
+}
+
+impl fmt::Display for DiversityStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "pairwise={:.3}, centroid={:.3}, variance={:.3}",
+            self.mean_pairwise_distance,
+            self.mean_centroid_distance,
+            self.avg_gene_variance(),
+        )
+    }
+}
+
+// Euclidean distance between two equally long gene (or behaviour) vectors.
+pub fn distance(a: &[f32], b: &[f32]) -> f32 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| (a - b).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test() {
+        let population = vec![
+            TestIndividual::with_genes(vec![0.0, 0.0]),
+            TestIndividual::with_genes(vec![4.0, 0.0]),
+            TestIndividual::with_genes(vec![0.0, 3.0]),
+            TestIndividual::with_genes(vec![4.0, 3.0]),
+        ];
+
+        let stats = DiversityStatistics::new(&population);
+
+        // 4 sides of 4.0 and 3.0, 2 diagonals of 5.0
+        assert_relative_eq!(stats.mean_pairwise_distance(), 4.0);
+        assert_relative_eq!(stats.mean_centroid_distance(), 2.5);
+        assert_relative_eq!(stats.gene_variances(), [4.0, 2.25].as_slice());
+        assert_relative_eq!(stats.avg_gene_variance(), 3.125);
+        assert_eq!(
+            stats.to_string(),
+            "pairwise=4.000, centroid=2.500, variance=3.125"
+        );
+    }
+
+    #[test]
+    fn test_converged() {
+        let population = vec![
+            TestIndividual::with_genes(vec![1.0, 2.0]),
+            TestIndividual::with_genes(vec![1.0, 2.0]),
+        ];
+
+        let stats = DiversityStatistics::new(&population);
+
+        assert_relative_eq!(stats.mean_pairwise_distance(), 0.0);
+        assert_relative_eq!(stats.mean_centroid_distance(), 0.0);
+        assert_relative_eq!(stats.avg_gene_variance(), 0.0);
+    }
+}
@@ -0,0 +1,460 @@
+use crate::*;
+
+// Operators picked at runtime (e.g. from a config file or a UI) rather than at
+// compile time.
+pub type DynSelection = Box<dyn DynSelectionMethod>;
+pub type DynCrossover = Box<dyn CrossoverMethod + Send + Sync>;
+pub type DynMutation = Box<dyn MutationMethod + Send + Sync>;
+pub type DynGeneticAlgorithm = GeneticAlgorithm<DynSelection, DynCrossover, DynMutation>;
+
+// Object-safe counterpart of `SelectionMethod`, which can't be boxed because
+// it's generic over the individual; implemented for every selection method.
+pub trait DynSelectionMethod: Send + Sync {
+    // Returns the index of an individual picked from the population.
+    fn select_index(&self, rng: &mut dyn RngCore, population: &[SharedIndividual]) -> usize;
+
+    // Returns the indices of `count` individuals picked from the population.
+    fn select_indices(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[SharedIndividual],
+        count: usize,
+    ) -> Vec<usize>;
+
+    fn validate_views(&self, population: &[SharedIndividual]) -> Result<(), Error>;
+
+    fn prefers_batched_views(&self) -> bool;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "name", rename_all = "snake_case")
+)]
+pub enum SelectionConfig {
+    RouletteWheel,
+    StochasticUniversal,
+    Tournament { size: usize, chance: f32 },
+    RankLinear { pressure: f32 },
+    RankExponential { base: f32 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "name", rename_all = "snake_case")
+)]
+pub enum CrossoverConfig {
+    Uniform,
+    SinglePoint,
+    KPoint { points: usize },
+    Arithmetic { alpha: f32 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "name", rename_all = "snake_case")
+)]
+pub enum MutationConfig {
+    Uniform { chance: f32, coeff: f32 },
+    Gaussian { chance: f32, coeff: f32 },
+    SelfAdaptiveSingleStep { initial_step: f32 },
+    SelfAdaptivePerGene { initial_step: f32 },
+}
+
+impl<S> DynSelectionMethod for S
+where
+    S: SelectionMethod + Send + Sync,
+{
+    fn select_index(&self, rng: &mut dyn RngCore, population: &[SharedIndividual]) -> usize {
+        index_of(population, self.select(rng, population))
+    }
+
+    fn select_indices(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[SharedIndividual],
+        count: usize,
+    ) -> Vec<usize> {
+        indices_of(population, &self.select_many(rng, population, count))
+    }
+
+    fn validate_views(&self, population: &[SharedIndividual]) -> Result<(), Error> {
+        self.validate(population)
+    }
+
+    fn prefers_batched_views(&self) -> bool {
+        self.prefers_batches()
+    }
+}
+
+impl SelectionMethod for DynSelection {
+    // Dispatches to the boxed method, not to the blanket impl of the box.
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        &population[(**self).select_index(rng, &views(population))]
+    }
+
+    // Builds the views once, rather than on every pick.
+    fn selector<'a, I>(&'a self, population: &'a [I]) -> Selector<'a, I>
+    where
+        I: Individual,
+    {
+        let views = views(population);
+
+        Box::new(move |rng| &population[(**self).select_index(rng, &views)])
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        (**self)
+            .select_indices(rng, &views(population), count)
+            .into_iter()
+            .map(|i| &population[i])
+            .collect()
+    }
+
+    fn validate<I>(&self, population: &[I]) -> Result<(), Error>
+    where
+        I: Individual,
+    {
+        (**self).validate_views(&views(population))
+    }
+
+    fn prefers_batches(&self) -> bool {
+        (**self).prefers_batched_views()
+    }
+}
+
+impl<C> CrossoverMethod for Box<C>
+where
+    C: CrossoverMethod + ?Sized,
+{
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &Chromosome,
+        parent_b: &Chromosome,
+    ) -> Chromosome {
+        (**self).crossover(rng, parent_a, parent_b)
+    }
+}
+
+impl<M> MutationMethod for Box<M>
+where
+    M: MutationMethod + ?Sized,
+{
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        (**self).mutate(rng, child)
+    }
+}
+
+impl SelectionConfig {
+    // Looks the operator up by its (snake_case) name, with default parameters.
+    pub fn from_name(name: &str) -> Result<Self, Error> {
+        match name {
+            "roulette_wheel" => Ok(Self::RouletteWheel),
+            "stochastic_universal" => Ok(Self::StochasticUniversal),
+            "tournament" => Ok(Self::Tournament {
+                size: 2,
+                chance: 0.75,
+            }),
+            "rank_linear" => Ok(Self::RankLinear { pressure: 1.5 }),
+            "rank_exponential" => Ok(Self::RankExponential { base: 0.95 }),
+            _ => Err(Error::UnknownOperator {
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn build(&self) -> Result<DynSelection, Error> {
+        Ok(match *self {
+            Self::RouletteWheel => Box::new(RouletteWheelSelection::new()),
+            Self::StochasticUniversal => Box::new(StochasticUniversalSampling::new()),
+            Self::Tournament { size, chance } => {
+                Box::new(TournamentSelection::try_new(size, chance)?)
+            }
+            Self::RankLinear { pressure } => Box::new(RankSelection::try_linear(pressure)?),
+            Self::RankExponential { base } => Box::new(RankSelection::try_exponential(base)?),
+        })
+    }
+}
+
+impl CrossoverConfig {
+    // Looks the operator up by its (snake_case) name, with default parameters.
+    pub fn from_name(name: &str) -> Result<Self, Error> {
+        match name {
+            "uniform" => Ok(Self::Uniform),
+            "single_point" => Ok(Self::SinglePoint),
+            "k_point" => Ok(Self::KPoint { points: 2 }),
+            "arithmetic" => Ok(Self::Arithmetic { alpha: 0.5 }),
+            _ => Err(Error::UnknownOperator {
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn build(&self) -> Result<DynCrossover, Error> {
+        Ok(match *self {
+            Self::Uniform => Box::new(UniformCrossover::new()),
+            Self::SinglePoint => Box::new(SinglePointCrossover::new()),
+            Self::KPoint { points } => Box::new(KPointCrossover::try_new(points)?),
+            Self::Arithmetic { alpha } => Box::new(ArithmeticCrossover::try_new(alpha)?),
+        })
+    }
+}
+
+impl MutationConfig {
+    // Looks the operator up by its (snake_case) name, with default parameters.
+    pub fn from_name(name: &str) -> Result<Self, Error> {
+        match name {
+            "uniform" => Ok(Self::Uniform {
+                chance: 0.01,
+                coeff: 0.3,
+            }),
+            "gaussian" => Ok(Self::Gaussian {
+                chance: 0.01,
+                coeff: 0.3,
+            }),
+            "self_adaptive_single_step" => Ok(Self::SelfAdaptiveSingleStep { initial_step: 0.1 }),
+            "self_adaptive_per_gene" => Ok(Self::SelfAdaptivePerGene { initial_step: 0.1 }),
+            _ => Err(Error::UnknownOperator {
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn build(&self) -> Result<DynMutation, Error> {
+        Ok(match *self {
+            Self::Uniform { chance, coeff } => Box::new(UniformMutation::try_new(chance, coeff)?),
+            Self::Gaussian { chance, coeff } => Box::new(GaussianMutation::try_new(chance, coeff)?),
+            Self::SelfAdaptiveSingleStep { initial_step } => {
+                Box::new(SelfAdaptiveMutation::try_single_step(initial_step)?)
+            }
+            Self::SelfAdaptivePerGene { initial_step } => {
+                Box::new(SelfAdaptiveMutation::try_per_gene(initial_step)?)
+            }
+        })
+    }
+}
+
+impl DynGeneticAlgorithm {
+    pub fn from_config(
+        selection: &SelectionConfig,
+        crossover: &CrossoverConfig,
+        mutation: &MutationConfig,
+    ) -> Result<Self, Error> {
+        Ok(Self::new(
+            selection.build()?,
+            crossover.build()?,
+            mutation.build()?,
+        ))
+    }
+}
+
+fn views<I>(population: &[I]) -> Vec<SharedIndividual<'_>>
+where
+    I: Individual,
+{
+    population
+        .iter()
+        .map(|individual| SharedIndividual {
+            chromosome: individual.to_chromosome(),
+            fitness: individual.fitness(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::with_genes(vec![0.0, 0.0, 0.0]),
+            TestIndividual::with_genes(vec![1.0, 1.0, 1.0]),
+            TestIndividual::with_genes(vec![1.0, 2.0, 1.0]),
+            TestIndividual::with_genes(vec![1.0, 2.0, 4.0]),
+        ]
+    }
+
+    // A boxed operator picks the same individuals as the concrete one.
+    #[test]
+    fn test_selection() {
+        let population = population();
+
+        let expected: Vec<*const TestIndividual> = RouletteWheelSelection::new()
+            .select_many(
+                &mut ChaCha8Rng::from_seed(Default::default()),
+                &population,
+                10,
+            )
+            .into_iter()
+            .map(|individual| individual as *const _)
+            .collect();
+
+        let actual: Vec<*const TestIndividual> = SelectionConfig::RouletteWheel
+            .build()
+            .unwrap()
+            .select_many(
+                &mut ChaCha8Rng::from_seed(Default::default()),
+                &population,
+                10,
+            )
+            .into_iter()
+            .map(|individual| individual as *const _)
+            .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        let population = vec![
+            TestIndividual::with_genes(vec![0.0]),
+            TestIndividual::with_genes(vec![0.0]),
+        ];
+
+        assert_eq!(
+            SelectionConfig::RouletteWheel
+                .build()
+                .unwrap()
+                .validate(&population),
+            Err(Error::ZeroTotalFitness)
+        );
+        assert_eq!(
+            SelectionConfig::from_name("tournament")
+                .unwrap()
+                .build()
+                .unwrap()
+                .validate(&population),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(
+            SelectionConfig::from_name("rank_linear"),
+            Ok(SelectionConfig::RankLinear { pressure: 1.5 })
+        );
+        assert_eq!(
+            CrossoverConfig::from_name("k_point"),
+            Ok(CrossoverConfig::KPoint { points: 2 })
+        );
+        assert_eq!(
+            MutationConfig::from_name("gaussian"),
+            Ok(MutationConfig::Gaussian {
+                chance: 0.01,
+                coeff: 0.3
+            })
+        );
+        assert_eq!(
+            MutationConfig::from_name("cauchy"),
+            Err(Error::UnknownOperator {
+                name: "cauchy".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_build_invalid() {
+        assert_eq!(
+            SelectionConfig::Tournament {
+                size: 0,
+                chance: 0.5
+            }
+            .build()
+            .err(),
+            Some(Error::InvalidParameter {
+                name: "tournament size",
+                value: 0.0
+            })
+        );
+        assert_eq!(
+            CrossoverConfig::Arithmetic { alpha: -1.0 }.build().err(),
+            Some(Error::InvalidParameter {
+                name: "blend alpha",
+                value: -1.0
+            })
+        );
+        assert_eq!(
+            DynGeneticAlgorithm::from_config(
+                &SelectionConfig::RouletteWheel,
+                &CrossoverConfig::Uniform,
+                &MutationConfig::Uniform {
+                    chance: 1.5,
+                    coeff: 0.3
+                },
+            )
+            .err(),
+            Some(Error::InvalidParameter {
+                name: "mutation chance",
+                value: 1.5
+            })
+        );
+    }
+
+    // Same operators, same RNG stream - boxing them changes nothing.
+    #[test]
+    fn test_genetic_algorithm() {
+        let static_ga = GeneticAlgorithm::new(
+            TournamentSelection::new(2, 0.75),
+            ArithmeticCrossover::new(0.5),
+            GaussianMutation::new(0.5, 0.5),
+        );
+
+        let dyn_ga = DynGeneticAlgorithm::from_config(
+            &SelectionConfig::from_name("tournament").unwrap(),
+            &CrossoverConfig::from_name("arithmetic").unwrap(),
+            &MutationConfig::Gaussian {
+                chance: 0.5,
+                coeff: 0.5,
+            },
+        )
+        .unwrap();
+
+        let mut static_rng = ChaCha8Rng::from_seed(Default::default());
+        let mut dyn_rng = ChaCha8Rng::from_seed(Default::default());
+        let mut static_population = population();
+        let mut dyn_population = population();
+
+        for _ in 0..10 {
+            static_population = static_ga.evolve(&mut static_rng, &static_population).0;
+            dyn_population = dyn_ga.evolve(&mut dyn_rng, &dyn_population).0;
+        }
+
+        assert_eq!(dyn_population, static_population);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialization() {
+        let json = r#"{"name":"tournament","size":3,"chance":0.5}"#;
+        let config: SelectionConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            config,
+            SelectionConfig::Tournament {
+                size: 3,
+                chance: 0.5
+            }
+        );
+        assert_eq!(serde_json::to_string(&config).unwrap(), json);
+    }
+}
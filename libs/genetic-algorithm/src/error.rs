@@ -0,0 +1,157 @@
+use crate::*;
+use std::fmt;
+
+// Reasons the fallible (`try_`) parts of the API can reject their input; the
+// infallible counterparts panic with the same message instead.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    EmptyPopulation,
+    EmptyChromosome,
+    MismatchedChromosomeLengths {
+        expected: usize,
+        actual: usize,
+    },
+    // Fitness is NaN, which can't be ranked.
+    NanFitness {
+        index: usize,
+    },
+    // Fitness is negative or infinite, which can't be used as a selection
+    // weight.
+    InvalidFitness {
+        index: usize,
+        fitness: f32,
+    },
+    // Every individual has zero fitness, so none can be picked by weight.
+    ZeroTotalFitness,
+    TooManyElites {
+        elite_count: usize,
+        population_size: usize,
+    },
+    // No operator is registered under that name.
+    UnknownOperator {
+        name: String,
+    },
+    // An operator's parameter is out of its valid range.
+    InvalidParameter {
+        name: &'static str,
+        value: f32,
+    },
+    // A restart policy has neither a stagnation window nor a minimum
+    // diversity, so it would never restart anything.
+    MissingRestartTrigger,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::EmptyPopulation => write!(f, "got an empty population"),
+            Self::EmptyChromosome => write!(f, "got an empty chromosome"),
+            Self::MismatchedChromosomeLengths { expected, actual } => write!(
+                f,
+                "got chromosomes of different lengths ({} and {})",
+                expected, actual
+            ),
+            Self::NanFitness { index } => write!(f, "individual #{} has NaN fitness", index),
+            Self::InvalidFitness { index, fitness } => write!(
+                f,
+                "individual #{} has fitness {}, which isn't a valid weight",
+                index, fitness
+            ),
+            Self::ZeroTotalFitness => write!(f, "every individual has zero fitness"),
+            Self::TooManyElites {
+                elite_count,
+                population_size,
+            } => write!(
+                f,
+                "got {} elites for a population of {}",
+                elite_count, population_size
+            ),
+            Self::UnknownOperator { name } => write!(f, "unknown operator: {}", name),
+            Self::InvalidParameter { name, value } => {
+                write!(f, "got an invalid {}: {}", name, value)
+            }
+            Self::MissingRestartTrigger => write!(f, "got a restart policy with no trigger"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// Fails on an empty population or on NaN fitness.
+pub(crate) fn check_fitness<I>(population: &[I]) -> Result<(), Error>
+where
+    I: Individual,
+{
+    if population.is_empty() {
+        return Err(Error::EmptyPopulation);
+    }
+
+    match population
+        .iter()
+        .position(|individual| individual.fitness().is_nan())
+    {
+        Some(index) => Err(Error::NanFitness { index }),
+        None => Ok(()),
+    }
+}
+
+// Fails with `InvalidParameter` unless `valid`.
+pub(crate) fn check_parameter(name: &'static str, value: f32, valid: bool) -> Result<(), Error> {
+    if valid {
+        Ok(())
+    } else {
+        Err(Error::InvalidParameter { name, value })
+    }
+}
+
+// Fails unless fitness can be used as a selection weight, i.e. is finite and
+// non-negative, and not zero for everyone.
+pub(crate) fn check_weights<I>(population: &[I]) -> Result<(), Error>
+where
+    I: Individual,
+{
+    check_fitness(population)?;
+
+    for (index, individual) in population.iter().enumerate() {
+        let fitness = individual.fitness();
+
+        if fitness < 0.0 || fitness.is_infinite() {
+            return Err(Error::InvalidFitness { index, fitness });
+        }
+    }
+
+    if population
+        .iter()
+        .all(|individual| individual.fitness() == 0.0)
+    {
+        return Err(Error::ZeroTotalFitness);
+    }
+
+    Ok(())
+}
+
+// Fails on empty chromosomes or on chromosomes of different lengths.
+pub(crate) fn check_lengths<'a>(
+    chromosomes: impl IntoIterator<Item = &'a Chromosome>,
+) -> Result<(), Error> {
+    let mut expected = None;
+
+    for chromosome in chromosomes {
+        if chromosome.is_empty() {
+            return Err(Error::EmptyChromosome);
+        }
+
+        match expected {
+            None => expected = Some(chromosome.len()),
+            Some(expected) if expected != chromosome.len() => {
+                return Err(Error::MismatchedChromosomeLengths {
+                    expected,
+                    actual: chromosome.len(),
+                });
+            }
+            Some(_) => (),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,280 @@
+use crate::*;
+
+// Bounded archive of the best distinct chromosomes ever seen, so that good
+// solutions aren't lost when the population regresses.
+//
+// Two chromosomes closer to each other (in Euclidean distance) than the
+// threshold count as the same solution, and only the fitter one is kept.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "HallOfFameRaw")
+)]
+pub struct HallOfFame {
+    capacity: usize,
+    threshold: f32,
+    // Sorted from the best to the worst.
+    members: Vec<(Chromosome, f32)>,
+}
+
+impl HallOfFame {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0);
+
+        Self {
+            capacity,
+            threshold: 0.0,
+            members: Vec::new(),
+        }
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        assert!(threshold >= 0.0);
+
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    // Best chromosome ever seen, together with its fitness.
+    pub fn best(&self) -> Option<(&Chromosome, f32)> {
+        self.members().next()
+    }
+
+    // Members from the best to the worst.
+    pub fn members(&self) -> impl Iterator<Item = (&Chromosome, f32)> + '_ {
+        self.members
+            .iter()
+            .map(|(chromosome, fitness)| (chromosome, *fitness))
+    }
+
+    // Considers every individual of an evaluated population; individuals with
+    // a non-finite fitness are ignored.
+    pub fn update<I>(&mut self, population: &[I])
+    where
+        I: Individual,
+    {
+        for individual in population {
+            let fitness = individual.fitness();
+
+            if fitness.is_finite() {
+                self.insert(individual.to_chromosome(), fitness);
+            }
+        }
+    }
+
+    // Replaces the `count` least fit individuals of an evaluated population
+    // with (clones of) the best `count` members.
+    pub fn inject<I>(&self, population: &mut [I], count: usize)
+    where
+        I: Individual,
+    {
+        let count = count.min(self.members.len()).min(population.len());
+
+        let mut ranked: Vec<usize> = (0..population.len()).collect();
+        ranked.sort_by(|&a, &b| population[a].fitness().total_cmp(&population[b].fitness()));
+
+        for (&i, (chromosome, _)) in ranked.iter().zip(&self.members).take(count) {
+            population[i] = I::from_chromosome(chromosome.clone());
+        }
+    }
+
+    fn insert(&mut self, chromosome: &Chromosome, fitness: f32) {
+        let duplicate = self.members.iter().position(|(member, _)| {
+            member.len() == chromosome.len()
+                && distance(member.genes(), chromosome.genes()) <= self.threshold
+        });
+
+        match duplicate {
+            Some(i) if self.members[i].1 >= fitness => return,
+            Some(i) => {
+                self.members.remove(i);
+            }
+            None if self.members.len() < self.capacity => (),
+            None if self.members[self.capacity - 1].1 < fitness => {
+                self.members.pop();
+            }
+            None => return,
+        }
+
+        let i = self
+            .members
+            .partition_point(|(_, member)| *member >= fitness);
+        self.members.insert(i, (chromosome.clone(), fitness));
+    }
+}
+
+// Deserialized as-is, then checked like in `new()` and `with_threshold()`;
+// members are sorted from the best to the worst, in case they were edited.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct HallOfFameRaw {
+    capacity: usize,
+    threshold: f32,
+    members: Vec<(Chromosome, f32)>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<HallOfFameRaw> for HallOfFame {
+    type Error = Error;
+
+    fn try_from(raw: HallOfFameRaw) -> Result<Self, Error> {
+        check_parameter(
+            "hall of fame capacity",
+            raw.capacity as f32,
+            raw.capacity > 0 && raw.members.len() <= raw.capacity,
+        )?;
+        check_parameter(
+            "hall of fame threshold",
+            raw.threshold,
+            raw.threshold >= 0.0,
+        )?;
+
+        let mut members = raw.members;
+        members.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        Ok(Self {
+            capacity: raw.capacity,
+            threshold: raw.threshold,
+            members,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn genes(hall_of_fame: &HallOfFame) -> Vec<Vec<f32>> {
+        hall_of_fame
+            .members()
+            .map(|(chromosome, _)| chromosome.genes().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_update() {
+        let mut hall_of_fame = HallOfFame::new(2);
+
+        hall_of_fame.update(&[
+            TestIndividual::with_genes(vec![1.0, 0.0]),
+            TestIndividual::with_genes(vec![2.0, 0.0]),
+            TestIndividual::with_genes(vec![0.0, 0.0]),
+        ]);
+
+        assert_eq!(genes(&hall_of_fame), vec![vec![2.0, 0.0], vec![1.0, 0.0]]);
+
+        // A worse generation doesn't push anyone out.
+        hall_of_fame.update(&[
+            TestIndividual::with_genes(vec![0.5, 0.0]),
+            TestIndividual::with_genes(vec![0.0, 0.0]),
+        ]);
+
+        assert_eq!(genes(&hall_of_fame), vec![vec![2.0, 0.0], vec![1.0, 0.0]]);
+
+        hall_of_fame.update(&[TestIndividual::with_genes(vec![3.0, 0.0])]);
+
+        assert_eq!(genes(&hall_of_fame), vec![vec![3.0, 0.0], vec![2.0, 0.0]]);
+        assert_eq!(hall_of_fame.best().unwrap().1, 3.0);
+    }
+
+    #[test]
+    fn test_update_ignores_non_finite_fitness() {
+        let mut hall_of_fame = HallOfFame::new(2);
+
+        hall_of_fame.update(&[
+            TestIndividual::with_genes(vec![f32::NAN]),
+            TestIndividual::with_genes(vec![f32::INFINITY]),
+        ]);
+
+        assert!(hall_of_fame.is_empty());
+    }
+
+    #[test]
+    fn test_deduplication() {
+        let mut hall_of_fame = HallOfFame::new(3);
+
+        hall_of_fame.update(&[
+            TestIndividual::with_genes(vec![1.0, 1.0]),
+            TestIndividual::with_genes(vec![1.0, 1.0]),
+        ]);
+
+        assert_eq!(genes(&hall_of_fame), vec![vec![1.0, 1.0]]);
+
+        let mut hall_of_fame = HallOfFame::new(3).with_threshold(0.5);
+
+        hall_of_fame.update(&[
+            TestIndividual::with_genes(vec![1.0, 1.0]),
+            TestIndividual::with_genes(vec![1.0, 1.25]),
+            TestIndividual::with_genes(vec![3.0, 0.0]),
+        ]);
+
+        // [1.0, 1.25] is close enough to [1.0, 1.0] to replace it.
+        assert_eq!(genes(&hall_of_fame), vec![vec![3.0, 0.0], vec![1.0, 1.25]]);
+    }
+
+    #[test]
+    fn test_inject() {
+        let mut hall_of_fame = HallOfFame::new(2);
+        hall_of_fame.update(&[
+            TestIndividual::with_genes(vec![5.0]),
+            TestIndividual::with_genes(vec![4.0]),
+        ]);
+
+        let mut population = vec![
+            TestIndividual::with_genes(vec![2.0]),
+            TestIndividual::with_genes(vec![0.0]),
+            TestIndividual::with_genes(vec![3.0]),
+            TestIndividual::with_genes(vec![1.0]),
+        ];
+
+        hall_of_fame.inject(&mut population, 2);
+
+        assert_eq!(
+            population,
+            vec![
+                TestIndividual::with_genes(vec![2.0]),
+                TestIndividual::with_genes(vec![5.0]),
+                TestIndividual::with_genes(vec![3.0]),
+                TestIndividual::with_genes(vec![4.0]),
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialization() {
+        let json = serde_json::to_string(&HallOfFame::new(2).with_threshold(0.5)).unwrap();
+        let hall_of_fame: HallOfFame = serde_json::from_str(&json).unwrap();
+        assert_eq!(hall_of_fame.capacity(), 2);
+
+        // hand-edited: out of order, and without strategy parameters
+        let json = r#"{"capacity":2,"threshold":0.0,"members":[[{"genes":[1.0]},1.0],[{"genes":[2.0]},2.0]]}"#;
+        let hall_of_fame: HallOfFame = serde_json::from_str(json).unwrap();
+        assert_eq!(genes(&hall_of_fame), vec![vec![2.0], vec![1.0]]);
+
+        for json in [
+            r#"{"capacity":0,"threshold":0.0,"members":[]}"#,
+            r#"{"capacity":1,"threshold":-1.0,"members":[]}"#,
+            r#"{"capacity":1,"threshold":0.0,"members":[[{"genes":[1.0],"strategy":[]},1.0],[{"genes":[2.0],"strategy":[]},2.0]]}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<HallOfFame>(json).is_err(),
+                "{}",
+                json
+            );
+        }
+    }
+}
@@ -6,6 +6,12 @@ pub trait Individual {
     fn to_chromosome(&self) -> &Chromosome;
 }
 
+// An individual judged by several, possibly conflicting, objectives rather than
+// a single fitness; like `Individual::fitness`, every objective is maximized.
+pub trait MultiObjectiveIndividual: Individual {
+    fn objectives(&self) -> Vec<f32>;
+}
+
 #[cfg(test)]
 #[derive(Clone, Debug, PartialEq)]
 pub enum TestIndividual {
@@ -18,6 +24,12 @@ impl TestIndividual {
     pub fn new(fitness: f32) -> Self {
         Self::WithFitness { fitness }
     }
+
+    pub fn with_genes(genes: Vec<f32>) -> Self {
+        Self::WithChromosome {
+            chromosome: Chromosome::new(genes),
+        }
+    }
 }
 
 #[cfg(test)]
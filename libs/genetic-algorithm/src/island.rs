@@ -0,0 +1,248 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MigrationTopology {
+    // Island i sends its migrants to island i + 1 (and the last one to the first).
+    Ring,
+    // Every island receives migrants picked at random from all the other islands.
+    FullyConnected,
+}
+
+// Evolves several subpopulations (islands) independently, every
+// `migration_interval` generations copying the `migrant_count` fittest
+// individuals of each island over to its neighbours.
+pub struct IslandModel<S, C, M> {
+    islands: Vec<GeneticAlgorithm<S, C, M>>,
+    topology: MigrationTopology,
+    migration_interval: usize,
+    migrant_count: usize,
+    generation: usize,
+}
+
+impl<S, C, M> IslandModel<S, C, M>
+where
+    S: SelectionMethod,
+    C: CrossoverMethod,
+    M: MutationMethod,
+{
+    pub fn new(
+        islands: Vec<GeneticAlgorithm<S, C, M>>,
+        topology: MigrationTopology,
+        migration_interval: usize,
+        migrant_count: usize,
+    ) -> Self {
+        assert!(!islands.is_empty());
+        assert!(migration_interval > 0);
+
+        Self {
+            islands,
+            topology,
+            migration_interval,
+            migrant_count,
+            generation: 0,
+        }
+    }
+
+    pub fn islands(&self) -> &[GeneticAlgorithm<S, C, M>] {
+        &self.islands
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // populations[i] is the current (evaluated) population of the i-th island.
+    pub fn evolve<I>(
+        &mut self,
+        rng: &mut dyn RngCore,
+        populations: &[Vec<I>],
+    ) -> (Vec<Vec<I>>, Vec<Statistics>)
+    where
+        I: Individual,
+    {
+        assert_eq!(populations.len(), self.islands.len());
+
+        let (mut offspring, stats): (Vec<_>, Vec<_>) = self
+            .islands
+            .iter()
+            .zip(populations)
+            .map(|(island, population)| island.evolve(rng, population))
+            .unzip();
+
+        self.generation += 1;
+
+        if self.islands.len() > 1 && self.generation.is_multiple_of(self.migration_interval) {
+            self.migrate(rng, populations, &mut offspring);
+        }
+
+        (offspring, stats)
+    }
+
+    // Migrants are taken from the evaluated populations (as only there is their
+    // fitness known) and replace the last, not yet evaluated, offspring of the
+    // receiving island, leaving its elites alone.
+    fn migrate<I>(&self, rng: &mut dyn RngCore, populations: &[Vec<I>], offspring: &mut [Vec<I>])
+    where
+        I: Individual,
+    {
+        let emigrants: Vec<Vec<&Chromosome>> = populations
+            .iter()
+            .map(|population| {
+                let mut ranked: Vec<&I> = population.iter().collect();
+                ranked.sort_by(|a, b| b.fitness().total_cmp(&a.fitness()));
+
+                ranked
+                    .into_iter()
+                    .take(self.migrant_count)
+                    .map(|individual| individual.to_chromosome())
+                    .collect()
+            })
+            .collect();
+
+        let island_cnt = self.islands.len();
+
+        for (i, offspring) in offspring.iter_mut().enumerate() {
+            let mut immigrants: Vec<&Chromosome> = match self.topology {
+                MigrationTopology::Ring => emigrants[(i + island_cnt - 1) % island_cnt].clone(),
+                MigrationTopology::FullyConnected => {
+                    let candidates: Vec<&Chromosome> = emigrants
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, _)| *j != i)
+                        .flat_map(|(_, emigrants)| emigrants.iter().copied())
+                        .collect();
+
+                    candidates
+                        .choose_multiple(rng, self.migrant_count)
+                        .copied()
+                        .collect()
+                }
+            };
+
+            // Small islands take in only as many immigrants as fit next to
+            // their elites.
+            immigrants.truncate(offspring.len().saturating_sub(self.islands[i].elite_count));
+
+            let start = offspring.len() - immigrants.len();
+            for (slot, immigrant) in offspring[start..].iter_mut().zip(immigrants) {
+                *slot = I::from_chromosome(immigrant.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn island() -> GeneticAlgorithm<TournamentSelection, UniformCrossover, UniformMutation> {
+        GeneticAlgorithm::new(
+            TournamentSelection::new(2, 1.0),
+            UniformCrossover::new(),
+            UniformMutation::new(0.0, 0.0),
+        )
+    }
+
+    fn populations() -> Vec<Vec<TestIndividual>> {
+        vec![
+            vec![
+                TestIndividual::with_genes(vec![1.0, 1.0]),
+                TestIndividual::with_genes(vec![1.0, 2.0]),
+            ],
+            vec![
+                TestIndividual::with_genes(vec![10.0, 10.0]),
+                TestIndividual::with_genes(vec![10.0, 20.0]),
+            ],
+            vec![
+                TestIndividual::with_genes(vec![100.0, 100.0]),
+                TestIndividual::with_genes(vec![100.0, 200.0]),
+            ],
+        ]
+    }
+
+    #[test]
+    fn test_ring() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = IslandModel::new(
+            vec![island(), island(), island()],
+            MigrationTopology::Ring,
+            2,
+            1,
+        );
+
+        let populations = populations();
+
+        // no migration in the first generation
+        let (offspring, stats) = model.evolve(&mut rng, &populations);
+        assert_eq!(stats.len(), 3);
+        assert!(offspring[1]
+            .iter()
+            .all(|individual| individual.fitness() >= 20.0));
+
+        let (offspring, _) = model.evolve(&mut rng, &populations);
+        assert_eq!(model.generation(), 2);
+        assert_eq!(
+            offspring[0][1],
+            TestIndividual::with_genes(vec![100.0, 200.0])
+        );
+        assert_eq!(offspring[1][1], TestIndividual::with_genes(vec![1.0, 2.0]));
+        assert_eq!(
+            offspring[2][1],
+            TestIndividual::with_genes(vec![10.0, 20.0])
+        );
+    }
+
+    #[test]
+    fn test_fully_connected() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = IslandModel::new(
+            vec![island(), island(), island()],
+            MigrationTopology::FullyConnected,
+            1,
+            1,
+        );
+
+        let (offspring, _) = model.evolve(&mut rng, &populations());
+
+        assert_eq!(
+            offspring[0][1],
+            TestIndividual::with_genes(vec![100.0, 200.0])
+        );
+        assert_eq!(
+            offspring[1][1],
+            TestIndividual::with_genes(vec![100.0, 200.0])
+        );
+        assert_eq!(
+            offspring[2][1],
+            TestIndividual::with_genes(vec![10.0, 20.0])
+        );
+    }
+
+    #[test]
+    fn test_more_migrants_than_room() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut model = IslandModel::new(
+            vec![
+                island().with_elite_count(1),
+                island().with_elite_count(1),
+                island().with_elite_count(1),
+            ],
+            MigrationTopology::Ring,
+            1,
+            5,
+        );
+
+        let (offspring, _) = model.evolve(&mut rng, &populations());
+
+        // only one of the five migrants fits next to each island's elite
+        assert!(offspring.iter().all(|offspring| offspring.len() == 2));
+        assert_eq!(offspring[0][0], TestIndividual::with_genes(vec![1.0, 2.0]));
+        assert_eq!(
+            offspring[0][1],
+            TestIndividual::with_genes(vec![100.0, 200.0])
+        );
+    }
+}
@@ -1,22 +1,45 @@
 pub use self::{
-    chromosome::*, crossover::*, individual::*, mutation::*, selection::*, statistics::*,
+    chromosome::*, cma_es::*, crossover::*, differential_evolution::*, diversity::*, dynamic::*,
+    error::*, hall_of_fame::*, individual::*, island::*, lineage::*, mutation::*, nsga2::*,
+    observer::*, optimizer::*, restart::*, run::*, selection::*, statistics::*, steady_state::*,
 };
 
 use rand::seq::SliceRandom;
 use rand::Rng;
 use rand::RngCore;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::sync::Mutex;
 
 mod chromosome;
+mod cma_es;
 mod crossover;
+mod differential_evolution;
+mod diversity;
+mod dynamic;
+mod error;
+mod hall_of_fame;
 mod individual;
+mod island;
+mod lineage;
 mod mutation;
+mod nsga2;
+mod observer;
+mod optimizer;
+mod restart;
+mod run;
 mod selection;
 mod statistics;
+mod steady_state;
 
 pub struct GeneticAlgorithm<S, C, M> {
     selection_method: S,
     crossover_method: C,
     mutation_method: M,
+    elite_count: usize,
+    observers: Mutex<Vec<Box<dyn EvolutionObserver>>>,
+    restart_policy: Option<RestartPolicy>,
+    restart_state: Mutex<RestartState>,
 }
 
 impl<S, C, M> GeneticAlgorithm<S, C, M>
@@ -30,37 +53,442 @@ where
             selection_method,
             crossover_method,
             mutation_method,
+            elite_count: 0,
+            observers: Mutex::new(Vec::new()),
+            restart_policy: None,
+            restart_state: Mutex::new(RestartState::new()),
         }
     }
 
+    // Number of the fittest individuals copied unchanged into the next
+    // generation; the rest of the population is bred as usual.
+    pub fn with_elite_count(mut self, elite_count: usize) -> Self {
+        self.elite_count = elite_count;
+        self
+    }
+
+    pub fn with_observer(mut self, observer: impl EvolutionObserver + 'static) -> Self {
+        self.observers.get_mut().unwrap().push(Box::new(observer));
+        self
+    }
+
+    // Restarts (or hypermutates) the population whenever it stagnates; see
+    // `RestartPolicy`, which needs a trigger configured.
+    pub fn with_restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        restart_policy
+            .check_trigger()
+            .unwrap_or_else(|err| panic!("{}", err));
+
+        self.restart_policy = Some(restart_policy);
+        self
+    }
+
+    // Number of times the population has been restarted so far.
+    pub fn restart_count(&self) -> usize {
+        self.restart_state.lock().unwrap().restarts()
+    }
+
+    // Like `evolve()`, but rejects populations it would otherwise panic on:
+    // empty ones, ones with NaN fitness, with chromosomes of different lengths
+    // or that the selection method can't pick from.
+    pub fn try_evolve<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+    ) -> Result<(Vec<I>, Statistics), Error>
+    where
+        I: Individual,
+    {
+        check_fitness(population)?;
+        check_lengths(
+            population
+                .iter()
+                .map(|individual| individual.to_chromosome()),
+        )?;
+        self.selection_method.validate(population)?;
+
+        if self.elite_count > population.len() {
+            return Err(Error::TooManyElites {
+                elite_count: self.elite_count,
+                population_size: population.len(),
+            });
+        }
+
+        Ok(self.evolve(rng, population))
+    }
+
     pub fn evolve<I>(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics)
+    where
+        I: Individual,
+    {
+        self.evolve_with(rng, population, |rng, population, count| {
+            self.breed(rng, population, count)
+        })
+    }
+
+    // Common part of `evolve()` and `evolve_par()`, which differ only in how
+    // the children get bred.
+    fn evolve_with<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        breed: impl FnOnce(&mut dyn RngCore, &[I], usize) -> Vec<I>,
+    ) -> (Vec<I>, Statistics)
     where
         I: Individual,
     {
         assert!(!population.is_empty());
+        assert!(self.elite_count <= population.len());
 
         let stats = Statistics::new(population);
 
+        if let Some(offspring) = self.restart(rng, population, &stats) {
+            return (offspring, stats);
+        }
+
         let mut offspring = Vec::with_capacity(population.len());
-        for _ in 0..population.len() {
-            let parent1 = self
-                .selection_method
-                .select(rng, population)
-                .to_chromosome();
-            let parent2 = self
-                .selection_method
-                .select(rng, population)
-                .to_chromosome();
 
-            let mut child = self.crossover_method.crossover(rng, parent1, parent2);
+        if self.elite_count > 0 {
+            let mut ranked: Vec<usize> = (0..population.len()).collect();
+            ranked.sort_by(|&a, &b| population[b].fitness().total_cmp(&population[a].fitness()));
+
+            let elites = &ranked[..self.elite_count];
 
-            self.mutation_method.mutate(rng, &mut child);
+            for &elite in elites {
+                offspring.push(I::from_chromosome(
+                    population[elite].to_chromosome().clone(),
+                ));
+            }
 
-            offspring.push(I::from_chromosome(child));
+            for observer in self.observers.lock().unwrap().iter_mut() {
+                observer.after_elitism(elites);
+            }
         }
 
+        let child_count = population.len() - self.elite_count;
+        offspring.extend(breed(rng, population, child_count));
+
+        self.generation_end(&stats, &offspring);
+
         (offspring, stats)
     }
+
+    // Keeps evolving the population until `controller` says to stop; only
+    // useful when an individual's fitness can be computed straight from its
+    // chromosome.
+    pub fn run<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: Vec<I>,
+        controller: &RunController,
+    ) -> (Vec<I>, RunSummary)
+    where
+        I: Individual,
+    {
+        let mut population = population;
+
+        let summary = controller.run(|| {
+            let (offspring, stats) = self.evolve(rng, &population);
+            population = offspring;
+            stats
+        });
+
+        (population, summary)
+    }
+
+    // Checks the restart policy and, if it calls for reseeding, returns the
+    // reseeded population.
+    fn restart<I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &[I],
+        stats: &Statistics,
+    ) -> Option<Vec<I>>
+    where
+        I: Individual,
+    {
+        let policy = self.restart_policy.as_ref()?;
+
+        let diversity = policy
+            .needs_diversity()
+            .then(|| DiversityStatistics::new(population).mean_pairwise_distance());
+
+        if !self
+            .restart_state
+            .lock()
+            .unwrap()
+            .update(policy, stats, diversity)
+        {
+            return None;
+        }
+
+        let RestartStrategy::Reseed { keep, gene_range } = policy.strategy() else {
+            return None;
+        };
+
+        let keep = keep.min(population.len());
+
+        let mut ranked: Vec<usize> = (0..population.len()).collect();
+        ranked.sort_by(|&a, &b| population[b].fitness().total_cmp(&population[a].fitness()));
+
+        let offspring: Vec<I> = ranked
+            .iter()
+            .enumerate()
+            .map(|(rank, &i)| {
+                let chromosome = population[i].to_chromosome();
+
+                if rank < keep {
+                    I::from_chromosome(chromosome.clone())
+                } else {
+                    I::from_chromosome(random_chromosome(rng, chromosome, gene_range))
+                }
+            })
+            .collect();
+
+        for observer in self.observers.lock().unwrap().iter_mut() {
+            observer.after_elitism(&ranked[..keep]);
+        }
+
+        self.generation_end(stats, &offspring);
+
+        Some(offspring)
+    }
+
+    fn generation_end<I>(&self, stats: &Statistics, offspring: &[I])
+    where
+        I: Individual,
+    {
+        let mut observers = self.observers.lock().unwrap();
+
+        if !observers.is_empty() {
+            let chromosomes: Vec<_> = offspring.iter().map(|i| i.to_chromosome()).collect();
+
+            for observer in observers.iter_mut() {
+                observer.generation_end(stats, &chromosomes);
+            }
+        }
+    }
+
+    // Creates `count` children from the (evaluated) population through
+    // selection, crossover and mutation.
+    pub fn breed<I>(&self, rng: &mut dyn RngCore, population: &[I], count: usize) -> Vec<I>
+    where
+        I: Individual,
+    {
+        self.breed_with(rng, population, count, |rng, mutation_passes, observed| {
+            let breed = |rng: &mut dyn RngCore, parent_a: &I, parent_b: &I| {
+                child(
+                    &self.crossover_method,
+                    &self.mutation_method,
+                    rng,
+                    parent_a.to_chromosome(),
+                    parent_b.to_chromosome(),
+                    mutation_passes,
+                    observed,
+                )
+            };
+
+            let mut parents = Vec::with_capacity(2 * count);
+            let mut children = Vec::with_capacity(count);
+
+            if self.selection_method.prefers_batches() {
+                parents = self
+                    .selection_method
+                    .select_many(rng, population, 2 * count);
+
+                for pair in parents.chunks(2) {
+                    children.push(breed(rng, pair[0], pair[1]));
+                }
+            } else {
+                // Parents are picked pair by pair, right before breeding.
+                let mut select = self.selection_method.selector(population);
+
+                for _ in 0..count {
+                    let parent_a = select(rng);
+                    let parent_b = select(rng);
+
+                    children.push(breed(rng, parent_a, parent_b));
+                    parents.extend([parent_a, parent_b]);
+                }
+            }
+
+            (parents, children)
+        })
+    }
+
+    // Common part of `breed()` and `breed_par()`: `make_children` returns the
+    // selected parents (two per child) and the children, each along with its
+    // pre-mutation copy if `observed`.
+    fn breed_with<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+        make_children: impl FnOnce(
+            &mut dyn RngCore,
+            usize,
+            bool,
+        ) -> (Vec<&'a I>, Vec<(Option<Chromosome>, Chromosome)>),
+    ) -> Vec<I>
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+
+        let mut observers = self.observers.lock().unwrap();
+        let observed = !observers.is_empty();
+
+        if observed {
+            let chromosomes: Vec<_> = population.iter().map(|i| i.to_chromosome()).collect();
+            let fitness: Vec<_> = population.iter().map(|i| i.fitness()).collect();
+
+            for observer in observers.iter_mut() {
+                observer.before_selection(&chromosomes, &fitness);
+            }
+        }
+
+        let mutation_passes = self
+            .restart_state
+            .lock()
+            .unwrap()
+            .mutation_passes(self.restart_policy.as_ref());
+
+        let (parents, children) = make_children(rng, mutation_passes, observed);
+
+        assert_eq!(children.len(), count);
+
+        if observed {
+            let parents = indices_of(population, &parents);
+
+            for observer in observers.iter_mut() {
+                observer.after_selection(&parents);
+            }
+
+            for (i, (crossed, mutated)) in children.iter().enumerate() {
+                let crossed = crossed.as_ref().unwrap();
+
+                for observer in observers.iter_mut() {
+                    observer.after_crossover(i, crossed);
+                    observer.after_mutation(i, crossed, mutated);
+                }
+            }
+        }
+
+        children
+            .into_iter()
+            .map(|(_, child)| I::from_chromosome(child))
+            .collect()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<S, C, M> GeneticAlgorithm<S, C, M>
+where
+    S: SelectionMethod,
+    C: CrossoverMethod + Sync,
+    M: MutationMethod + Sync,
+{
+    // Like `evolve()`, but breeds the children in parallel; see `breed_par()`.
+    pub fn evolve_par<I>(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics)
+    where
+        I: Individual,
+    {
+        self.evolve_with(rng, population, |rng, population, count| {
+            self.breed_par(rng, population, count)
+        })
+    }
+
+    // Like `breed()`, but while parents are still selected sequentially, every
+    // child is then bred on its own RNG seeded from `rng`, so results are
+    // reproducible regardless of the number of threads (though they differ
+    // from `breed()`'s).
+    pub fn breed_par<I>(&self, rng: &mut dyn RngCore, population: &[I], count: usize) -> Vec<I>
+    where
+        I: Individual,
+    {
+        use rand::SeedableRng;
+        use rand_chacha::ChaCha8Rng;
+
+        self.breed_with(rng, population, count, |rng, mutation_passes, observed| {
+            let parents = self
+                .selection_method
+                .select_many(rng, population, 2 * count);
+
+            let chromosomes: Vec<&Chromosome> = parents
+                .iter()
+                .map(|parent| parent.to_chromosome())
+                .collect();
+
+            let seeds: Vec<u64> = (0..count).map(|_| rng.next_u64()).collect();
+            let crossover_method = &self.crossover_method;
+            let mutation_method = &self.mutation_method;
+
+            let children = chromosomes
+                .par_chunks(2)
+                .zip(seeds)
+                .map(|(parents, seed)| {
+                    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+                    child(
+                        crossover_method,
+                        mutation_method,
+                        &mut rng,
+                        parents[0],
+                        parents[1],
+                        mutation_passes,
+                        observed,
+                    )
+                })
+                .collect();
+
+            (parents, children)
+        })
+    }
+}
+
+// Returns the child, along with its pre-mutation copy if `observed`.
+fn child(
+    crossover_method: &impl CrossoverMethod,
+    mutation_method: &impl MutationMethod,
+    rng: &mut dyn RngCore,
+    parent1: &Chromosome,
+    parent2: &Chromosome,
+    mutation_passes: usize,
+    observed: bool,
+) -> (Option<Chromosome>, Chromosome) {
+    let mut child = crossover_method.crossover(rng, parent1, parent2);
+
+    child.inherit_strategy(parent1, parent2);
+
+    let crossed = observed.then(|| child.clone());
+
+    for _ in 0..mutation_passes {
+        mutation_method.mutate(rng, &mut child);
+    }
+
+    (crossed, child)
+}
+
+// Maps individuals picked from `population` back to their indices.
+fn indices_of<I>(population: &[I], individuals: &[&I]) -> Vec<usize> {
+    individuals
+        .iter()
+        .map(|individual| index_of(population, individual))
+        .collect()
+}
+
+// Index of an individual borrowed from `population`, worked out from its
+// address, as individuals needn't be comparable.
+fn index_of<I>(population: &[I], individual: &I) -> usize {
+    let size = std::mem::size_of::<I>().max(1);
+    let offset = (individual as *const I as usize).wrapping_sub(population.as_ptr() as usize);
+
+    assert!(
+        offset.is_multiple_of(size) && offset / size < population.len(),
+        "selected individual isn't part of the population"
+    );
+
+    offset / size
 }
 
 #[cfg(test)]
@@ -72,9 +500,7 @@ mod tests {
     fn individual(genes: Vec<f32>) -> TestIndividual {
         let chromosome: Chromosome = Chromosome::new(genes);
 
-        TestIndividual::WithChromosome {
-            chromosome: chromosome,
-        }
+        TestIndividual::WithChromosome { chromosome }
     }
 
     #[test]
@@ -84,7 +510,7 @@ mod tests {
         let ga = GeneticAlgorithm::new(
             RouletteWheelSelection::new(),
             UniformCrossover::new(),
-            GaussianMutation::new(0.5, 0.5),
+            UniformMutation::new(0.5, 0.5),
         );
 
         let mut population = vec![
@@ -107,4 +533,409 @@ mod tests {
 
         assert_eq!(population, expected)
     }
+
+    #[test]
+    fn test_elitism() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            TournamentSelection::new(2, 0.75),
+            UniformCrossover::new(),
+            UniformMutation::new(1.0, 2.0),
+        )
+        .with_elite_count(1);
+
+        let mut population = vec![
+            individual(vec![0.0, 0.0, 0.0]),
+            individual(vec![1.0, 1.0, 1.0]),
+            individual(vec![1.0, 2.0, 1.0]),
+            individual(vec![1.0, 2.0, 4.0]),
+        ];
+
+        let mut max_fitness = Statistics::new(&population).max_fitness();
+
+        for _ in 0..100 {
+            population = ga.evolve(&mut rng, &population).0;
+
+            let stats = Statistics::new(&population);
+            assert!(stats.max_fitness() >= max_fitness);
+
+            max_fitness = stats.max_fitness();
+        }
+
+        assert_eq!(population.len(), 4);
+    }
+
+    #[test]
+    fn test_self_adaptive_mutation() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            SelfAdaptiveMutation::single_step(0.5),
+        );
+
+        let mut population = vec![
+            individual(vec![0.0, 0.0, 0.0]),
+            individual(vec![1.0, 1.0, 1.0]),
+            individual(vec![1.0, 2.0, 1.0]),
+            individual(vec![1.0, 2.0, 4.0]),
+        ];
+
+        for _ in 0..10 {
+            population = ga.evolve(&mut rng, &population).0;
+        }
+
+        let expected = vec![
+            Chromosome::new(vec![2.6174242, 3.6680937, 2.0969284]).with_strategy(vec![0.1954737]),
+            Chromosome::new(vec![2.2430398, 3.6774511, 1.9225789]).with_strategy(vec![0.1404814]),
+            Chromosome::new(vec![3.1772645, 3.3156664, 2.565274]).with_strategy(vec![0.1480152]),
+            Chromosome::new(vec![1.8439842, 3.4463186, 1.7469399]).with_strategy(vec![0.43184134]),
+        ];
+
+        let actual: Vec<_> = population
+            .iter()
+            .map(|individual| individual.to_chromosome().clone())
+            .collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    #[cfg(feature = "rayon")]
+    fn test_parallel_determinism() {
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            GaussianMutation::new(0.5, 0.5),
+        );
+
+        let run = |threads: usize| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+
+            pool.install(|| {
+                let mut rng = ChaCha8Rng::from_seed(Default::default());
+                let mut population: Vec<_> = (0..50)
+                    .map(|n| individual(vec![n as f32, 1.0, 2.0]))
+                    .collect();
+
+                for _ in 0..10 {
+                    population = ga.evolve_par(&mut rng, &population).0;
+                }
+
+                population
+            })
+        };
+
+        assert_eq!(run(1), run(4));
+    }
+
+    #[test]
+    fn test_try_evolve() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            UniformCrossover::new(),
+            UniformMutation::new(0.5, 0.5),
+        )
+        .with_elite_count(2);
+
+        let cases = [
+            (
+                vec![individual(vec![1.0, 1.0]), individual(vec![1.0])],
+                Error::MismatchedChromosomeLengths {
+                    expected: 2,
+                    actual: 1,
+                },
+            ),
+            (
+                vec![individual(vec![0.0]), individual(vec![0.0])],
+                Error::ZeroTotalFitness,
+            ),
+            (
+                vec![individual(vec![1.0]), individual(vec![f32::NAN])],
+                Error::NanFitness { index: 1 },
+            ),
+            (
+                vec![individual(vec![1.0])],
+                Error::TooManyElites {
+                    elite_count: 2,
+                    population_size: 1,
+                },
+            ),
+        ];
+
+        for (population, expected) in cases {
+            assert_eq!(ga.try_evolve(&mut rng, &population).unwrap_err(), expected);
+        }
+
+        let population = vec![individual(vec![1.0]), individual(vec![2.0])];
+
+        assert_eq!(ga.try_evolve(&mut rng, &population).unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn test_try_evolve_operators() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let zero_fitness = vec![individual(vec![0.0]), individual(vec![0.0])];
+
+        let ga = GeneticAlgorithm::new(
+            StochasticUniversalSampling::new(),
+            UniformCrossover::new(),
+            UniformMutation::new(0.5, 0.5),
+        );
+
+        assert_eq!(
+            ga.try_evolve(&mut rng, &zero_fitness).unwrap_err(),
+            Error::ZeroTotalFitness
+        );
+
+        assert_eq!(
+            ga.try_evolve(&mut rng, &[individual(vec![1.0]), individual(vec![-1.0])])
+                .unwrap_err(),
+            Error::InvalidFitness {
+                index: 1,
+                fitness: -1.0
+            }
+        );
+
+        let ga = GeneticAlgorithm::new(
+            FitnessSharing::new(RouletteWheelSelection::new(), 1.0, 1.0),
+            UniformCrossover::new(),
+            UniformMutation::new(0.5, 0.5),
+        );
+
+        assert_eq!(
+            ga.try_evolve(&mut rng, &zero_fitness).unwrap_err(),
+            Error::ZeroTotalFitness
+        );
+
+        // one-gene chromosomes can't be cut, so they're just copied
+        let ga = GeneticAlgorithm::new(
+            RouletteWheelSelection::new(),
+            SinglePointCrossover::new(),
+            UniformMutation::new(0.0, 0.0),
+        );
+
+        let population = vec![individual(vec![1.0]), individual(vec![2.0])];
+        let (offspring, _) = ga.try_evolve(&mut rng, &population).unwrap();
+
+        assert!(offspring
+            .iter()
+            .all(|individual| population.contains(individual)));
+    }
+
+    #[test]
+    fn test_run() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            TournamentSelection::new(2, 1.0),
+            UniformCrossover::new(),
+            GaussianMutation::new(0.5, 0.5),
+        )
+        .with_elite_count(1);
+
+        let population = vec![
+            individual(vec![0.0, 0.0, 0.0]),
+            individual(vec![1.0, 1.0, 1.0]),
+            individual(vec![1.0, 2.0, 1.0]),
+            individual(vec![1.0, 2.0, 4.0]),
+        ];
+
+        let controller = RunController::new()
+            .with_max_generations(1000)
+            .with_target_fitness(20.0);
+
+        let (population, summary) = ga.run(&mut rng, population, &controller);
+
+        assert_eq!(summary.stop_reason(), StopReason::TargetFitness);
+        assert!(summary.generations() < 1000);
+        assert_eq!(population.len(), 4);
+    }
+
+    #[test]
+    fn test_restart_reseed() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            TournamentSelection::new(2, 1.0),
+            UniformCrossover::new(),
+            UniformMutation::new(0.0, 0.0),
+        )
+        .with_restart_policy(
+            RestartPolicy::new(RestartStrategy::Reseed {
+                keep: 1,
+                gene_range: 10.0,
+            })
+            .with_stagnation_window(1),
+        );
+
+        let mut population = vec![individual(vec![1.0, 1.0]); 4];
+
+        population = ga.evolve(&mut rng, &population).0;
+        assert_eq!(ga.restart_count(), 0);
+        assert_eq!(population, vec![individual(vec![1.0, 1.0]); 4]);
+
+        population = ga.evolve(&mut rng, &population).0;
+        assert_eq!(ga.restart_count(), 1);
+        assert_eq!(population[0], individual(vec![1.0, 1.0]));
+
+        for individual in &population[1..] {
+            assert!(individual
+                .to_chromosome()
+                .iter()
+                .all(|gene| gene.abs() <= 10.0));
+            assert_ne!(individual.to_chromosome().genes(), &vec![1.0, 1.0]);
+        }
+    }
+
+    #[test]
+    fn test_restart_hypermutation() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Default)]
+        struct CountingMutation {
+            calls: AtomicUsize,
+        }
+
+        impl MutationMethod for CountingMutation {
+            fn mutate(&self, _rng: &mut dyn RngCore, _child: &mut Chromosome) {
+                self.calls.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            TournamentSelection::new(2, 1.0),
+            UniformCrossover::new(),
+            CountingMutation::default(),
+        )
+        .with_restart_policy(
+            RestartPolicy::new(RestartStrategy::Hypermutation {
+                multiplier: 3,
+                generations: 1,
+            })
+            .with_stagnation_window(2),
+        );
+
+        let mut population = vec![individual(vec![1.0, 1.0]); 4];
+        let mut calls = Vec::new();
+
+        for _ in 0..4 {
+            population = ga.evolve(&mut rng, &population).0;
+            calls.push(ga.mutation_method.calls.swap(0, Ordering::Relaxed));
+        }
+
+        assert_eq!(calls, vec![4, 4, 12, 4]);
+        assert_eq!(ga.restart_count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "got a restart policy with no trigger")]
+    fn test_restart_without_trigger() {
+        let _ = GeneticAlgorithm::new(
+            TournamentSelection::new(2, 1.0),
+            UniformCrossover::new(),
+            UniformMutation::new(0.0, 0.0),
+        )
+        .with_restart_policy(RestartPolicy::new(RestartStrategy::Reseed {
+            keep: 1,
+            gene_range: 10.0,
+        }));
+    }
+
+    #[test]
+    fn test_observer() {
+        use std::sync::Arc;
+
+        #[derive(Default)]
+        struct Recorder {
+            events: Arc<Mutex<Vec<String>>>,
+        }
+
+        impl EvolutionObserver for Recorder {
+            fn before_selection(&mut self, population: &[&Chromosome], fitness: &[f32]) {
+                self.events.lock().unwrap().push(format!(
+                    "before_selection({}, {:?})",
+                    population.len(),
+                    fitness
+                ));
+            }
+
+            fn after_selection(&mut self, parents: &[usize]) {
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push(format!("after_selection({:?})", parents));
+            }
+
+            fn after_crossover(&mut self, child_index: usize, _: &Chromosome) {
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push(format!("after_crossover({})", child_index));
+            }
+
+            fn after_mutation(
+                &mut self,
+                child_index: usize,
+                before: &Chromosome,
+                after: &Chromosome,
+            ) {
+                self.events.lock().unwrap().push(format!(
+                    "after_mutation({}, {})",
+                    child_index,
+                    before == after
+                ));
+            }
+
+            fn generation_end(&mut self, stats: &Statistics, offspring: &[&Chromosome]) {
+                self.events.lock().unwrap().push(format!(
+                    "generation_end({}, {})",
+                    stats.max_fitness(),
+                    offspring.len()
+                ));
+            }
+        }
+
+        let recorder = Recorder::default();
+        let events = recorder.events.clone();
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let ga = GeneticAlgorithm::new(
+            TournamentSelection::new(3, 1.0),
+            UniformCrossover::new(),
+            GaussianMutation::new(0.0, 0.0),
+        )
+        .with_elite_count(1)
+        .with_observer(recorder);
+
+        let population = vec![
+            individual(vec![0.0, 0.0]),
+            individual(vec![1.0, 1.0]),
+            individual(vec![1.0, 2.0]),
+        ];
+
+        ga.evolve(&mut rng, &population);
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![
+                "before_selection(3, [0.0, 2.0, 3.0])",
+                "after_selection([2, 2, 2, 1])",
+                "after_crossover(0)",
+                "after_mutation(0, true)",
+                "after_crossover(1)",
+                "after_mutation(1, true)",
+                "generation_end(3, 3)",
+            ]
+        );
+    }
 }
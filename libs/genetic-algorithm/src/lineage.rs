@@ -0,0 +1,345 @@
+use crate::*;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+
+// Genealogy of every individual bred by a `GeneticAlgorithm`: each one gets a
+// unique ID, and its parents' IDs, generation and number of mutated genes are
+// recorded.
+//
+// `Lineage` is an `EvolutionObserver`; it's a handle to shared state, so keep
+// a clone around to query it after registering it with `with_observer()`:
+//
+//     let lineage = Lineage::new();
+//     let ga = GeneticAlgorithm::new(..).with_observer(lineage.clone());
+//
+// IDs are matched to individuals by position, so each generation returned by
+// `evolve()` has to be passed back to it in the same order. Elites keep their
+// IDs. `SteadyState` replaces individuals in place and isn't supported.
+#[derive(Clone, Debug, Default)]
+pub struct Lineage {
+    graph: Arc<Mutex<LineageGraph>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineageNode {
+    id: usize,
+    generation: usize,
+    parents: Vec<usize>,
+    mutations: usize,
+    fitness: Option<f32>,
+}
+
+#[derive(Debug, Default)]
+struct LineageGraph {
+    nodes: Vec<LineageNode>,
+    // IDs of the current population, in order.
+    current: Vec<usize>,
+    generation: usize,
+    elites: Vec<usize>,
+    parents: Vec<usize>,
+    mutations: Vec<usize>,
+}
+
+impl Lineage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // IDs of the most recent generation, in the same order as the population.
+    pub fn current_ids(&self) -> Vec<usize> {
+        self.graph.lock().unwrap().current.clone()
+    }
+
+    pub fn node(&self, id: usize) -> Option<LineageNode> {
+        self.graph.lock().unwrap().nodes.get(id).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.graph.lock().unwrap().nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Every ancestor of the given individual, most recent first; `None` if
+    // there's no individual with that ID.
+    pub fn ancestors(&self, id: usize) -> Option<Vec<LineageNode>> {
+        let graph = self.graph.lock().unwrap();
+
+        let mut seen = vec![false; graph.nodes.len()];
+        let mut pending = graph.nodes.get(id)?.parents.clone();
+        let mut ancestors = Vec::new();
+
+        while let Some(id) = pending.pop() {
+            if std::mem::replace(&mut seen[id], true) {
+                continue;
+            }
+
+            pending.extend(&graph.nodes[id].parents);
+            ancestors.push(graph.nodes[id].clone());
+        }
+
+        ancestors.sort_by_key(|node| std::cmp::Reverse(node.id));
+        Some(ancestors)
+    }
+
+    // Graphviz representation, with edges pointing from parents to children.
+    pub fn to_dot(&self) -> String {
+        let graph = self.graph.lock().unwrap();
+        let mut dot = String::from("digraph lineage {\n");
+
+        for node in &graph.nodes {
+            let fitness = node
+                .fitness
+                .map(|fitness| format!("\\nfitness={:.2}", fitness))
+                .unwrap_or_default();
+
+            writeln!(
+                dot,
+                "    {} [label=\"#{}\\ngen={}\\nmutations={}{}\"];",
+                node.id, node.id, node.generation, node.mutations, fitness
+            )
+            .unwrap();
+
+            for parent in &node.parents {
+                writeln!(dot, "    {} -> {};", parent, node.id).unwrap();
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    pub fn to_json(&self) -> String {
+        let graph = self.graph.lock().unwrap();
+
+        let nodes: Vec<String> = graph
+            .nodes
+            .iter()
+            .map(|node| {
+                let parents: Vec<String> = node.parents.iter().map(|id| id.to_string()).collect();
+                let fitness = match node.fitness {
+                    Some(fitness) if fitness.is_finite() => fitness.to_string(),
+                    _ => "null".to_string(),
+                };
+
+                format!(
+                    "{{\"id\":{},\"generation\":{},\"parents\":[{}],\"mutations\":{},\"fitness\":{}}}",
+                    node.id,
+                    node.generation,
+                    parents.join(","),
+                    node.mutations,
+                    fitness
+                )
+            })
+            .collect();
+
+        format!("{{\"nodes\":[{}]}}", nodes.join(","))
+    }
+}
+
+impl LineageNode {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    // Generation the individual was born in; the initial population is 0.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // Empty for the initial population, two IDs for everyone else.
+    pub fn parents(&self) -> &[usize] {
+        &self.parents
+    }
+
+    // Number of genes changed by mutation.
+    pub fn mutations(&self) -> usize {
+        self.mutations
+    }
+
+    // Fitness the individual was evaluated with, if it has been evaluated yet.
+    pub fn fitness(&self) -> Option<f32> {
+        self.fitness
+    }
+}
+
+impl LineageGraph {
+    fn add(&mut self, parents: Vec<usize>, mutations: usize) -> usize {
+        let id = self.nodes.len();
+
+        self.nodes.push(LineageNode {
+            id,
+            generation: self.generation,
+            parents,
+            mutations,
+            fitness: None,
+        });
+
+        id
+    }
+}
+
+impl EvolutionObserver for Lineage {
+    fn before_selection(&mut self, population: &[&Chromosome], fitness: &[f32]) {
+        let mut graph = self.graph.lock().unwrap();
+
+        // The initial population, or one that wasn't bred by us.
+        if graph.current.len() != population.len() {
+            graph.current = (0..population.len())
+                .map(|_| graph.add(Vec::new(), 0))
+                .collect();
+        }
+
+        for (i, &fitness) in fitness.iter().enumerate() {
+            let id = graph.current[i];
+            graph.nodes[id].fitness = Some(fitness);
+        }
+    }
+
+    fn after_elitism(&mut self, elites: &[usize]) {
+        let mut graph = self.graph.lock().unwrap();
+
+        graph.elites = elites.to_vec();
+    }
+
+    fn after_selection(&mut self, parents: &[usize]) {
+        let mut graph = self.graph.lock().unwrap();
+
+        graph.parents = parents.iter().map(|&i| graph.current[i]).collect();
+        graph.mutations.clear();
+    }
+
+    fn after_mutation(&mut self, _child_index: usize, before: &Chromosome, after: &Chromosome) {
+        let mutations = before
+            .iter()
+            .zip(after.iter())
+            .filter(|(before, after)| before != after)
+            .count();
+
+        self.graph.lock().unwrap().mutations.push(mutations);
+    }
+
+    fn generation_end(&mut self, _stats: &Statistics, offspring: &[&Chromosome]) {
+        let mut graph = self.graph.lock().unwrap();
+        let graph = &mut *graph;
+
+        graph.generation += 1;
+
+        let mut current: Vec<usize> = graph.elites.drain(..).map(|i| graph.current[i]).collect();
+
+        let parents = std::mem::take(&mut graph.parents);
+        let mutations = std::mem::take(&mut graph.mutations);
+
+        for (parents, mutations) in parents.chunks(2).zip(mutations) {
+            current.push(graph.add(parents.to_vec(), mutations));
+        }
+
+        // Individuals created from scratch, e.g. by a restart.
+        while current.len() < offspring.len() {
+            current.push(graph.add(Vec::new(), 0));
+        }
+
+        assert_eq!(current.len(), offspring.len());
+        graph.current = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn lineage() -> Lineage {
+        let lineage = Lineage::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let ga = GeneticAlgorithm::new(
+            TournamentSelection::new(2, 1.0),
+            UniformCrossover::new(),
+            UniformMutation::new(0.5, 0.5),
+        )
+        .with_elite_count(1)
+        .with_observer(lineage.clone());
+
+        let mut population = vec![
+            TestIndividual::with_genes(vec![0.0, 0.0]),
+            TestIndividual::with_genes(vec![1.0, 1.0]),
+            TestIndividual::with_genes(vec![1.0, 2.0]),
+        ];
+
+        for _ in 0..2 {
+            population = ga.evolve(&mut rng, &population).0;
+        }
+
+        lineage
+    }
+
+    #[test]
+    fn test() {
+        let lineage = lineage();
+
+        // 3 initial individuals + 2 children in each of the 2 generations
+        assert_eq!(lineage.len(), 7);
+        assert_eq!(lineage.current_ids(), vec![2, 5, 6]);
+
+        let node = lineage.node(6).unwrap();
+        assert_eq!(node.generation(), 2);
+        assert_eq!(node.parents(), &[4, 2]);
+        assert_eq!(node.mutations(), 1);
+        assert_eq!(node.fitness(), None);
+
+        let node = lineage.node(2).unwrap();
+        assert_eq!(node.generation(), 0);
+        assert!(node.parents().is_empty());
+        assert_eq!(node.fitness(), Some(3.0));
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let ids: Vec<usize> = lineage()
+            .ancestors(6)
+            .unwrap()
+            .iter()
+            .map(|node| node.id())
+            .collect();
+
+        assert_eq!(ids, vec![4, 2, 1]);
+        assert_eq!(lineage().ancestors(7), None);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let dot = lineage().to_dot();
+
+        assert!(dot.starts_with("digraph lineage {\n"));
+        assert!(
+            dot.contains("    6 [label=\"#6\\ngen=2\\nmutations=1\"];\n    4 -> 6;\n    2 -> 6;\n")
+        );
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json = lineage().to_json();
+
+        assert!(json.starts_with(
+            r#"{"nodes":[{"id":0,"generation":0,"parents":[],"mutations":0,"fitness":0},"#
+        ));
+        assert!(json.ends_with(
+            r#"{"id":6,"generation":2,"parents":[4,2],"mutations":1,"fitness":null}]}"#
+        ));
+    }
+
+    #[test]
+    fn test_ids_follow_population() {
+        let lineage = lineage();
+        let ids = lineage.current_ids();
+
+        assert_eq!(ids.len(), 3);
+        assert!(ids.iter().all(|&id| id < lineage.len()));
+    }
+}
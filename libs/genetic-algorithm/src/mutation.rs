@@ -1,8 +1,10 @@
-pub use self::gaussian::*;
+pub use self::{gaussian::*, self_adaptive::*, uniform::*};
 
 use crate::*;
 
 mod gaussian;
+mod self_adaptive;
+mod uniform;
 
 pub trait MutationMethod {
     fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome);
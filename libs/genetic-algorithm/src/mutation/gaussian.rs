@@ -1,6 +1,14 @@
 use crate::*;
+use rand_distr::{Distribution, Normal};
 
+// Adds normally distributed noise to each gene with probability `chance`;
+// `coeff` is the standard deviation of that noise.
 #[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "GaussianMutationRaw")
+)]
 pub struct GaussianMutation {
     chance: f32,
     coeff: f32,
@@ -8,20 +16,46 @@ pub struct GaussianMutation {
 
 impl GaussianMutation {
     pub fn new(chance: f32, coeff: f32) -> GaussianMutation {
-        GaussianMutation { chance, coeff }
+        GaussianMutation::try_new(chance, coeff).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(chance: f32, coeff: f32) -> Result<GaussianMutation, Error> {
+        check_parameter("mutation chance", chance, (0.0..=1.0).contains(&chance))?;
+        check_parameter("mutation coeff", coeff, coeff >= 0.0)?;
+
+        Ok(GaussianMutation { chance, coeff })
     }
 }
 
 impl MutationMethod for GaussianMutation {
     fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        let noise = Normal::new(0.0, self.coeff).expect("invalid standard deviation");
+
         for gene in child.iter_mut() {
             if rng.gen_bool(self.chance as _) {
-                *gene += rng.gen_range(-self.coeff..=self.coeff);
+                *gene += noise.sample(rng);
             }
         }
     }
 }
 
+// Deserialized as-is, then checked by `try_new()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct GaussianMutationRaw {
+    chance: f32,
+    coeff: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<GaussianMutationRaw> for GaussianMutation {
+    type Error = Error;
+
+    fn try_from(raw: GaussianMutationRaw) -> Result<Self, Error> {
+        Self::try_new(raw.chance, raw.coeff)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,100 +70,75 @@ mod tests {
 
         GaussianMutation::new(chance, coeff).mutate(&mut rng, &mut child);
 
-        let mut genes = Vec::with_capacity(child.len());
-        for i in 0..child.len() {
-            genes.push(child[i]);
-        }
-
-        genes
+        child.genes().clone()
     }
 
-    fn no_change(chance: f32, coeff: f32) {
-        let actual = actual(chance, coeff);
+    #[test]
+    fn test_zero_chance() {
         let expected = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
 
+        let actual = actual(0.0, 1.0);
+
         assert_relative_eq!(actual.as_slice(), expected.as_slice());
     }
 
-    fn change(chance: f32, coeff: f32, expected: Vec<f32>) {
-        let actual = actual(chance, coeff);
+    #[test]
+    fn test_zero_coefficient() {
+        let expected = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+
+        let actual = actual(1.0, 0.0);
 
         assert_relative_eq!(actual.as_slice(), expected.as_slice());
     }
 
-    mod zero_chance {
-        use super::*;
+    #[test]
+    fn test_half_chance() {
+        let expected = vec![1.0, 2.0, 1.064953, 2.935384, 3.997438, 6.3935523, 7.0];
 
-        mod and_zero_coefficient {
-            use super::*;
+        let actual = actual(0.5, 1.0);
 
-            #[test]
-            fn test() {
-                no_change(0.0, 0.0);
-            }
-        }
+        assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
 
-        mod and_nonzero_coefficient {
-            use super::*;
+    #[test]
+    fn test_max_chance() {
+        let expected = vec![
+            2.3776972, 2.4053469, 1.8037311, 2.0649529, 4.328226, 4.935384, 4.8510237,
+        ];
 
-            #[test]
-            fn test() {
-                no_change(0.0, 1.0);
-            }
-        }
-    }
+        let actual = actual(1.0, 1.0);
 
-    mod half_chance {
-        use super::*;
+        assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
 
-        mod and_zero_coefficient {
-            use super::*;
+    #[test]
+    fn test_standard_deviation() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = Chromosome::new(vec![0.0; 10_000]);
 
-            #[test]
-            fn test() {
-                no_change(0.5, 0.0);
-            }
-        }
+        GaussianMutation::new(1.0, 0.3).mutate(&mut rng, &mut child);
 
-        mod and_nonzero_coefficient {
-            use super::*;
+        let mean = child.iter().sum::<f32>() / child.len() as f32;
+        let variance =
+            child.iter().map(|gene| (gene - mean).powi(2)).sum::<f32>() / child.len() as f32;
 
-            #[test]
-            fn test() {
-                change(
-                    0.5,
-                    1.0,
-                    vec![1.0, 2.0, 3.0693698, 3.5112038, 5.2754607, 6.0, 6.638722],
-                );
-            }
-        }
+        assert_relative_eq!(mean, 0.0, epsilon = 0.01);
+        assert_relative_eq!(variance.sqrt(), 0.3, epsilon = 0.01);
     }
 
-    mod max_chance {
-        use super::*;
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialization() {
+        let json = serde_json::to_string(&GaussianMutation::new(0.5, 0.25)).unwrap();
+        assert_eq!(json, r#"{"chance":0.5,"coeff":0.25}"#);
 
-        mod and_zero_coefficient {
-            use super::*;
+        let mutation: GaussianMutation = serde_json::from_str(&json).unwrap();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = Chromosome::new(vec![0.0; 100]);
 
-            #[test]
-            fn test() {
-                no_change(1.0, 0.0);
-            }
-        }
+        mutation.mutate(&mut rng, &mut child);
 
-        mod and_nonzero_coefficient {
-            use super::*;
-
-            #[test]
-            fn test() {
-                change(
-                    1.0,
-                    1.0,
-                    vec![
-                        0.3744812, 2.6738396, 3.8181262, 4.262849, 5.523881, 5.464832, 7.06937,
-                    ],
-                );
-            }
-        }
+        assert!(child.iter().any(|&gene| gene != 0.0));
+        assert!(child.iter().any(|&gene| gene == 0.0));
     }
 }
@@ -0,0 +1,168 @@
+use crate::*;
+use rand_distr::{Distribution, StandardNormal};
+
+const MIN_STEP: f32 = 1e-5;
+
+// Evolution-strategy-style mutation: every chromosome carries its own step
+// size(s) in `Chromosome::strategy`, which are mutated log-normally before
+// being used as the standard deviation of the genes' Gaussian noise.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "SelfAdaptiveMutationRaw")
+)]
+pub struct SelfAdaptiveMutation {
+    initial_step: f32,
+    per_gene: bool,
+}
+
+impl SelfAdaptiveMutation {
+    // One step size shared by all genes of a chromosome.
+    pub fn single_step(initial_step: f32) -> Self {
+        Self::try_single_step(initial_step).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_single_step(initial_step: f32) -> Result<Self, Error> {
+        Self::try_new(initial_step, false)
+    }
+
+    // A separate step size for every gene of a chromosome.
+    pub fn per_gene(initial_step: f32) -> Self {
+        Self::try_per_gene(initial_step).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_per_gene(initial_step: f32) -> Result<Self, Error> {
+        Self::try_new(initial_step, true)
+    }
+
+    fn try_new(initial_step: f32, per_gene: bool) -> Result<Self, Error> {
+        check_parameter("initial step", initial_step, initial_step > 0.0)?;
+
+        Ok(Self {
+            initial_step,
+            per_gene,
+        })
+    }
+
+    fn step_count(&self, child: &Chromosome) -> usize {
+        if self.per_gene {
+            child.len()
+        } else {
+            1
+        }
+    }
+}
+
+impl MutationMethod for SelfAdaptiveMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        let step_count = self.step_count(child);
+
+        // Chromosomes that don't carry (matching) step sizes yet, e.g. ones from
+        // the initial population, start from `initial_step`.
+        if child.strategy.len() != step_count {
+            child.strategy = vec![self.initial_step; step_count];
+        }
+
+        let n = child.len() as f32;
+        let global_rate = 1.0 / (2.0 * n).sqrt();
+        let local_rate = 1.0 / (2.0 * n.sqrt()).sqrt();
+
+        let global_noise: f32 = StandardNormal.sample(rng);
+
+        for step in child.strategy.iter_mut() {
+            let local_noise: f32 = if self.per_gene {
+                StandardNormal.sample(rng)
+            } else {
+                0.0
+            };
+
+            *step *= (global_rate * global_noise + local_rate * local_noise).exp();
+            *step = step.max(MIN_STEP);
+        }
+
+        for i in 0..child.len() {
+            let step = child.strategy[i % step_count];
+            let noise: f32 = StandardNormal.sample(rng);
+
+            child.genes[i] += step * noise;
+        }
+    }
+}
+
+// Deserialized as-is, then checked by `try_new()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SelfAdaptiveMutationRaw {
+    initial_step: f32,
+    per_gene: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SelfAdaptiveMutationRaw> for SelfAdaptiveMutation {
+    type Error = Error;
+
+    fn try_from(raw: SelfAdaptiveMutationRaw) -> Result<Self, Error> {
+        Self::try_new(raw.initial_step, raw.per_gene)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(mutation: SelfAdaptiveMutation, child: Chromosome) -> Chromosome {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut child = child;
+
+        mutation.mutate(&mut rng, &mut child);
+
+        child
+    }
+
+    #[test]
+    fn test_single_step() {
+        let child = actual(
+            SelfAdaptiveMutation::single_step(0.5),
+            Chromosome::new(vec![1.0, 2.0, 3.0, 4.0]),
+        );
+
+        assert_relative_eq!(
+            child.genes().as_slice(),
+            [1.3298657, 1.026493, 1.4252856, 3.4533198].as_slice()
+        );
+        assert_relative_eq!(child.strategy().as_slice(), [0.81378615].as_slice());
+    }
+
+    #[test]
+    fn test_per_gene() {
+        let child = actual(
+            SelfAdaptiveMutation::per_gene(0.5),
+            Chromosome::new(vec![1.0, 2.0, 3.0, 4.0]),
+        );
+
+        assert_relative_eq!(
+            child.genes().as_slice(),
+            [-0.061019182, 1.0384421, 2.6899502, 3.7996242].as_slice()
+        );
+        assert_relative_eq!(
+            child.strategy().as_slice(),
+            [0.9966215, 0.44744927, 0.30925745, 0.58161616].as_slice()
+        );
+    }
+
+    #[test]
+    fn test_existing_strategy() {
+        let child = actual(
+            SelfAdaptiveMutation::per_gene(0.5),
+            Chromosome::new(vec![1.0, 2.0, 3.0, 4.0]).with_strategy(vec![0.0, 0.0, 1.0, 1.0]),
+        );
+
+        assert_relative_eq!(child.genes()[..2], [1.0, 2.0], epsilon = 1e-4);
+        assert_relative_eq!(child.strategy()[..2], [MIN_STEP, MIN_STEP]);
+        assert!(child.strategy()[2] > MIN_STEP);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::*;
+
+// Adds noise drawn uniformly from [-coeff, coeff] to each gene with
+// probability `chance`.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "UniformMutationRaw")
+)]
+pub struct UniformMutation {
+    chance: f32,
+    coeff: f32,
+}
+
+impl UniformMutation {
+    pub fn new(chance: f32, coeff: f32) -> UniformMutation {
+        UniformMutation::try_new(chance, coeff).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(chance: f32, coeff: f32) -> Result<UniformMutation, Error> {
+        check_parameter("mutation chance", chance, (0.0..=1.0).contains(&chance))?;
+        check_parameter("mutation coeff", coeff, coeff >= 0.0)?;
+
+        Ok(UniformMutation { chance, coeff })
+    }
+}
+
+impl MutationMethod for UniformMutation {
+    fn mutate(&self, rng: &mut dyn RngCore, child: &mut Chromosome) {
+        for gene in child.iter_mut() {
+            if rng.gen_bool(self.chance as _) {
+                *gene += rng.gen_range(-self.coeff..=self.coeff);
+            }
+        }
+    }
+}
+
+// Deserialized as-is, then checked by `try_new()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct UniformMutationRaw {
+    chance: f32,
+    coeff: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<UniformMutationRaw> for UniformMutation {
+    type Error = Error;
+
+    fn try_from(raw: UniformMutationRaw) -> Result<Self, Error> {
+        Self::try_new(raw.chance, raw.coeff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn actual(chance: f32, coeff: f32) -> Vec<f32> {
+        let mut child = Chromosome::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        UniformMutation::new(chance, coeff).mutate(&mut rng, &mut child);
+
+        let mut genes = Vec::with_capacity(child.len());
+        for i in 0..child.len() {
+            genes.push(child[i]);
+        }
+
+        genes
+    }
+
+    fn no_change(chance: f32, coeff: f32) {
+        let actual = actual(chance, coeff);
+        let expected = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+
+        assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    fn change(chance: f32, coeff: f32, expected: Vec<f32>) {
+        let actual = actual(chance, coeff);
+
+        assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    mod zero_chance {
+        use super::*;
+
+        mod and_zero_coefficient {
+            use super::*;
+
+            #[test]
+            fn test() {
+                no_change(0.0, 0.0);
+            }
+        }
+
+        mod and_nonzero_coefficient {
+            use super::*;
+
+            #[test]
+            fn test() {
+                no_change(0.0, 1.0);
+            }
+        }
+    }
+
+    mod half_chance {
+        use super::*;
+
+        mod and_zero_coefficient {
+            use super::*;
+
+            #[test]
+            fn test() {
+                no_change(0.5, 0.0);
+            }
+        }
+
+        mod and_nonzero_coefficient {
+            use super::*;
+
+            #[test]
+            fn test() {
+                change(
+                    0.5,
+                    1.0,
+                    vec![1.0, 2.0, 3.0693698, 3.5112038, 5.2754607, 6.0, 6.638722],
+                );
+            }
+        }
+    }
+
+    mod max_chance {
+        use super::*;
+
+        mod and_zero_coefficient {
+            use super::*;
+
+            #[test]
+            fn test() {
+                no_change(1.0, 0.0);
+            }
+        }
+
+        mod and_nonzero_coefficient {
+            use super::*;
+
+            #[test]
+            fn test() {
+                change(
+                    1.0,
+                    1.0,
+                    vec![
+                        0.3744812, 2.6738396, 3.8181262, 4.262849, 5.523881, 5.464832, 7.06937,
+                    ],
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "got an invalid mutation chance: 1.5")]
+    fn test_invalid_chance() {
+        UniformMutation::new(1.5, 0.5);
+    }
+}
@@ -0,0 +1,505 @@
+use crate::*;
+use std::cmp::Ordering;
+use std::sync::Mutex;
+
+// Multi-objective evolution (NSGA-II): individuals are ranked into Pareto fronts
+// by non-dominated sorting, and parents are picked by binary tournaments that
+// prefer a better front and, within the same front, a less crowded region.
+pub struct Nsga2<C, M> {
+    crossover_method: C,
+    mutation_method: M,
+    // The previous generation's survivors, kept by `Optimizer::evolve()` to
+    // compete with their evaluated offspring.
+    survivors: Mutex<Survivors>,
+}
+
+#[derive(Default)]
+struct Survivors {
+    chromosomes: Vec<Chromosome>,
+    objectives: Vec<Vec<f32>>,
+    fronts: Vec<Vec<usize>>,
+}
+
+impl<C, M> Nsga2<C, M>
+where
+    C: CrossoverMethod,
+    M: MutationMethod,
+{
+    pub fn new(crossover_method: C, mutation_method: M) -> Self {
+        Self {
+            crossover_method,
+            mutation_method,
+            survivors: Mutex::new(Survivors::default()),
+        }
+    }
+
+    // Breeds a new population from the evaluated one, also returning the
+    // latter's Pareto fronts (as indices into `population`, best front first).
+    pub fn evolve<I>(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Vec<Vec<usize>>)
+    where
+        I: MultiObjectiveIndividual,
+    {
+        assert!(!population.is_empty());
+
+        let chromosomes: Vec<_> = population
+            .iter()
+            .map(|individual| individual.to_chromosome())
+            .collect();
+
+        let objectives = objectives(population);
+        let fronts = non_dominated_sort(&objectives);
+
+        let offspring = self
+            .mate(rng, &chromosomes, &objectives, &fronts)
+            .into_iter()
+            .map(I::from_chromosome)
+            .collect();
+
+        (offspring, fronts)
+    }
+
+    // Elitist environmental selection: keeps the `count` best individuals of an
+    // evaluated population (usually parents and their offspring combined),
+    // filling up front by front and breaking the last front's ties in favour
+    // of less crowded individuals.
+    pub fn survivors<I>(&self, population: Vec<I>, count: usize) -> Vec<I>
+    where
+        I: MultiObjectiveIndividual,
+    {
+        assert!(count <= population.len());
+
+        let mut keep = vec![false; population.len()];
+
+        for i in truncate(&objectives(&population), count)
+            .into_iter()
+            .flatten()
+        {
+            keep[i] = true;
+        }
+
+        population
+            .into_iter()
+            .zip(keep)
+            .filter(|(_, keep)| *keep)
+            .map(|(individual, _)| individual)
+            .collect()
+    }
+
+    // Objectives of the survivors `Optimizer::evolve()` last bred from,
+    // grouped into Pareto fronts (best first).
+    pub fn pareto_fronts(&self) -> Vec<Vec<Vec<f32>>> {
+        let survivors = self.survivors.lock().unwrap();
+
+        survivors
+            .fronts
+            .iter()
+            .map(|front| {
+                front
+                    .iter()
+                    .map(|&i| survivors.objectives[i].clone())
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Picks parents by crowded binary tournaments and breeds one child per
+    // member of `chromosomes`.
+    fn mate(
+        &self,
+        rng: &mut dyn RngCore,
+        chromosomes: &[&Chromosome],
+        objectives: &[Vec<f32>],
+        fronts: &[Vec<usize>],
+    ) -> Vec<Chromosome> {
+        let mut ranks = vec![0; chromosomes.len()];
+        let mut distances = vec![0.0; chromosomes.len()];
+        for (rank, front) in fronts.iter().enumerate() {
+            for (&i, distance) in front.iter().zip(crowding_distances(objectives, front)) {
+                ranks[i] = rank;
+                distances[i] = distance;
+            }
+        }
+
+        let select = |rng: &mut dyn RngCore| {
+            let a = rng.gen_range(0..chromosomes.len());
+            let b = rng.gen_range(0..chromosomes.len());
+
+            let winner = match ranks[a].cmp(&ranks[b]) {
+                Ordering::Less => a,
+                Ordering::Greater => b,
+                Ordering::Equal if distances[a] >= distances[b] => a,
+                Ordering::Equal => b,
+            };
+
+            chromosomes[winner]
+        };
+
+        (0..chromosomes.len())
+            .map(|_| {
+                let parent1 = select(rng);
+                let parent2 = select(rng);
+
+                let mut child = self.crossover_method.crossover(rng, parent1, parent2);
+                child.inherit_strategy(parent1, parent2);
+
+                self.mutation_method.mutate(rng, &mut child);
+
+                child
+            })
+            .collect()
+    }
+}
+
+// Lets NSGA-II stand in for any other optimizer, with elitism: the evaluated
+// population competes with the survivors it was bred from, and the best of
+// both (see `survivors()`) become the next parents. The statistics describe
+// the individuals' scalar `fitness()`.
+impl<C, M, I> Optimizer<I> for Nsga2<C, M>
+where
+    C: CrossoverMethod,
+    M: MutationMethod,
+    I: MultiObjectiveIndividual,
+{
+    fn evolve(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics) {
+        assert!(!population.is_empty());
+
+        let mut survivors = self.survivors.lock().unwrap();
+
+        let chromosomes: Vec<_> = survivors
+            .chromosomes
+            .drain(..)
+            .chain(population.iter().map(|i| i.to_chromosome().clone()))
+            .collect();
+
+        let objectives: Vec<_> = survivors
+            .objectives
+            .drain(..)
+            .chain(population.iter().map(|i| i.objectives()))
+            .collect();
+
+        let fronts = truncate(&objectives, population.len());
+
+        // Renumbers the kept individuals, preserving their order.
+        let mut kept: Vec<_> = fronts.iter().flatten().copied().collect();
+        kept.sort_unstable();
+
+        let mut renumbered = vec![0; objectives.len()];
+        for (new, &old) in kept.iter().enumerate() {
+            renumbered[old] = new;
+        }
+
+        survivors.fronts = fronts
+            .into_iter()
+            .map(|front| front.into_iter().map(|i| renumbered[i]).collect())
+            .collect();
+
+        for (i, (chromosome, objectives)) in chromosomes.into_iter().zip(objectives).enumerate() {
+            if kept.binary_search(&i).is_ok() {
+                survivors.chromosomes.push(chromosome);
+                survivors.objectives.push(objectives);
+            }
+        }
+
+        let parents: Vec<_> = survivors.chromosomes.iter().collect();
+
+        let offspring = self
+            .mate(rng, &parents, &survivors.objectives, &survivors.fronts)
+            .into_iter()
+            .map(I::from_chromosome)
+            .collect();
+
+        (offspring, Statistics::new(population))
+    }
+
+    fn pareto_fronts(&self) -> Option<Vec<Vec<Vec<f32>>>> {
+        Some(Nsga2::pareto_fronts(self))
+    }
+}
+
+fn objectives<I>(population: &[I]) -> Vec<Vec<f32>>
+where
+    I: MultiObjectiveIndividual,
+{
+    population
+        .iter()
+        .map(|individual| individual.objectives())
+        .collect()
+}
+
+// Pareto fronts of the `count` best individuals: whole fronts, best first,
+// with the last one that doesn't fit cut down to its least crowded members.
+fn truncate(objectives: &[Vec<f32>], count: usize) -> Vec<Vec<usize>> {
+    let mut fronts = Vec::new();
+    let mut kept = 0;
+
+    for front in non_dominated_sort(objectives) {
+        if kept == count {
+            break;
+        }
+
+        if kept + front.len() <= count {
+            kept += front.len();
+            fronts.push(front);
+        } else {
+            let distances = crowding_distances(objectives, &front);
+
+            let mut front: Vec<_> = front.into_iter().zip(distances).collect();
+            front.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+            let mut front: Vec<_> = front
+                .into_iter()
+                .take(count - kept)
+                .map(|(i, _)| i)
+                .collect();
+            front.sort_unstable();
+
+            fronts.push(front);
+            break;
+        }
+    }
+
+    fronts
+}
+
+// Whether `a` is at least as good as `b` in every objective, and strictly
+// better in at least one.
+pub fn dominates(a: &[f32], b: &[f32]) -> bool {
+    assert_eq!(a.len(), b.len());
+
+    a.iter().zip(b).all(|(a, b)| a >= b) && a.iter().zip(b).any(|(a, b)| a > b)
+}
+
+// Splits the population into Pareto fronts: the first one holds every
+// non-dominated individual, the second one those dominated only by members of
+// the first one, and so on.
+pub fn non_dominated_sort(objectives: &[Vec<f32>]) -> Vec<Vec<usize>> {
+    let n = objectives.len();
+
+    let mut dominated: Vec<Vec<usize>> = vec![Vec::new(); n];
+    let mut domination_counts = vec![0; n];
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if dominates(&objectives[i], &objectives[j]) {
+                dominated[i].push(j);
+                domination_counts[j] += 1;
+            } else if dominates(&objectives[j], &objectives[i]) {
+                dominated[j].push(i);
+                domination_counts[i] += 1;
+            }
+        }
+    }
+
+    let mut fronts = Vec::new();
+    let mut front: Vec<usize> = (0..n).filter(|&i| domination_counts[i] == 0).collect();
+
+    while !front.is_empty() {
+        let mut next_front = Vec::new();
+
+        for &i in &front {
+            for &j in &dominated[i] {
+                domination_counts[j] -= 1;
+
+                if domination_counts[j] == 0 {
+                    next_front.push(j);
+                }
+            }
+        }
+
+        next_front.sort_unstable();
+        fronts.push(front);
+        front = next_front;
+    }
+
+    fronts
+}
+
+// Crowding distance of every member of `front` (in the same order): the sum,
+// over all objectives, of the normalized gap between its two neighbours.
+// Boundary individuals get an infinite distance, so they're always preferred.
+pub fn crowding_distances(objectives: &[Vec<f32>], front: &[usize]) -> Vec<f32> {
+    let mut distances = vec![0.0; front.len()];
+
+    if front.len() <= 2 {
+        return vec![f32::INFINITY; front.len()];
+    }
+
+    let objective_cnt = objectives[front[0]].len();
+
+    let columns = (0..objective_cnt).map(|objective| {
+        front
+            .iter()
+            .map(|&i| objectives[i][objective])
+            .collect::<Vec<f32>>()
+    });
+
+    for values in columns {
+        let mut sorted: Vec<usize> = (0..front.len()).collect();
+        sorted.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+
+        let first = sorted[0];
+        let last = sorted[sorted.len() - 1];
+        let range = values[last] - values[first];
+
+        distances[first] = f32::INFINITY;
+        distances[last] = f32::INFINITY;
+
+        if range <= 0.0 {
+            continue;
+        }
+
+        for neighbours in sorted.windows(3) {
+            distances[neighbours[1]] += (values[neighbours[2]] - values[neighbours[0]]) / range;
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    // Objectives are the chromosome's genes.
+    #[derive(Debug, PartialEq)]
+    struct TestMultiObjectiveIndividual {
+        chromosome: Chromosome,
+    }
+
+    impl Individual for TestMultiObjectiveIndividual {
+        fn from_chromosome(chromosome: Chromosome) -> Self {
+            Self { chromosome }
+        }
+
+        fn fitness(&self) -> f32 {
+            self.chromosome.iter().sum()
+        }
+
+        fn to_chromosome(&self) -> &Chromosome {
+            &self.chromosome
+        }
+    }
+
+    impl MultiObjectiveIndividual for TestMultiObjectiveIndividual {
+        fn objectives(&self) -> Vec<f32> {
+            self.chromosome.genes().clone()
+        }
+    }
+
+    fn individual(genes: Vec<f32>) -> TestMultiObjectiveIndividual {
+        TestMultiObjectiveIndividual::from_chromosome(Chromosome::new(genes))
+    }
+
+    fn population() -> Vec<TestMultiObjectiveIndividual> {
+        vec![
+            individual(vec![1.0, 5.0]), // front 0
+            individual(vec![2.0, 2.0]), // front 1
+            individual(vec![3.0, 3.0]), // front 0
+            individual(vec![5.0, 1.0]), // front 0
+            individual(vec![1.0, 1.0]), // front 2
+            individual(vec![4.0, 2.0]), // front 0
+        ]
+    }
+
+    #[test]
+    fn test_dominates() {
+        assert!(dominates(&[2.0, 2.0], &[1.0, 2.0]));
+        assert!(!dominates(&[2.0, 2.0], &[2.0, 2.0]));
+        assert!(!dominates(&[3.0, 1.0], &[1.0, 3.0]));
+    }
+
+    #[test]
+    fn test_non_dominated_sort() {
+        let actual = non_dominated_sort(&objectives(&population()));
+        let expected = vec![vec![0, 2, 3, 5], vec![1], vec![4]];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_crowding_distances() {
+        let actual = crowding_distances(&objectives(&population()), &[0, 2, 3, 5]);
+
+        // [3, 3] neighbours [1, 5] and [4, 2]: (4 - 1) / 4 + (5 - 2) / 4
+        // [4, 2] neighbours [3, 3] and [5, 1]: (5 - 3) / 4 + (3 - 1) / 4
+        let expected = [f32::INFINITY, 1.5, f32::INFINITY, 1.0];
+
+        assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_survivors() {
+        let nsga2 = Nsga2::new(UniformCrossover::new(), UniformMutation::new(0.0, 0.0));
+
+        let actual = nsga2.survivors(population(), 3);
+        let expected = vec![
+            individual(vec![1.0, 5.0]),
+            individual(vec![3.0, 3.0]),
+            individual(vec![5.0, 1.0]),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let nsga2 = Nsga2::new(UniformCrossover::new(), UniformMutation::new(0.0, 0.0));
+
+        let (offspring, fronts) = nsga2.evolve(&mut rng, &population());
+
+        assert_eq!(fronts, vec![vec![0, 2, 3, 5], vec![1], vec![4]]);
+
+        let (optimizer_offspring, stats) = Optimizer::evolve(
+            &nsga2,
+            &mut ChaCha8Rng::from_seed(Default::default()),
+            &population(),
+        );
+
+        assert_eq!(optimizer_offspring, offspring);
+        assert_eq!(stats.population_size(), 6);
+        assert_eq!(
+            offspring
+                .iter()
+                .map(|individual| individual.objectives())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![4.0, 2.0],
+                vec![5.0, 1.0],
+                vec![5.0, 1.0],
+                vec![3.0, 3.0],
+                vec![3.0, 3.0],
+                vec![5.0, 3.0],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_optimizer_keeps_survivors() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let nsga2 = Nsga2::new(UniformCrossover::new(), UniformMutation::new(0.0, 0.0));
+
+        Optimizer::evolve(&nsga2, &mut rng, &population());
+
+        // Offspring that are all worse than their parents don't push them out.
+        let offspring: Vec<_> = (0..6).map(|_| individual(vec![0.0, 0.0])).collect();
+        Optimizer::evolve(&nsga2, &mut rng, &offspring);
+
+        assert_eq!(
+            nsga2.pareto_fronts(),
+            vec![
+                vec![
+                    vec![1.0, 5.0],
+                    vec![3.0, 3.0],
+                    vec![5.0, 1.0],
+                    vec![4.0, 2.0]
+                ],
+                vec![vec![2.0, 2.0]],
+                vec![vec![1.0, 1.0]],
+            ]
+        );
+    }
+}
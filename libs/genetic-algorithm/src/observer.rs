@@ -0,0 +1,28 @@
+use crate::*;
+
+// Hooks into `GeneticAlgorithm`'s breeding, e.g. for logging or collecting
+// metrics; every callback does nothing by default.
+//
+// Callbacks are always invoked on the thread that called `evolve()` (or
+// `breed()`), in order, even when offspring are bred in parallel; observers
+// only have to be `Send` so that `GeneticAlgorithm` stays `Send + Sync`.
+pub trait EvolutionObserver: Send {
+    // The population about to be bred from, with each individual's fitness.
+    fn before_selection(&mut self, _population: &[&Chromosome], _fitness: &[f32]) {}
+
+    // Indices (into the population) of the elites copied into the next
+    // generation, which come first in it.
+    fn after_elitism(&mut self, _elites: &[usize]) {}
+
+    // Indices (into the population) of the selected parents; child `i` is bred
+    // from parents `2 * i` and `2 * i + 1`.
+    fn after_selection(&mut self, _parents: &[usize]) {}
+
+    fn after_crossover(&mut self, _child_index: usize, _child: &Chromosome) {}
+
+    fn after_mutation(&mut self, _child_index: usize, _before: &Chromosome, _after: &Chromosome) {}
+
+    // The whole new generation (elites included), together with the
+    // statistics of the one it was bred from.
+    fn generation_end(&mut self, _stats: &Statistics, _offspring: &[&Chromosome]) {}
+}
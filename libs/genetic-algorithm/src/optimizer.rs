@@ -0,0 +1,38 @@
+use crate::*;
+
+// Anything that turns an evaluated population into the next one to evaluate,
+// so callers can swap e.g. `GeneticAlgorithm` for `DifferentialEvolution`.
+pub trait Optimizer<I> {
+    // Returns the next population, along with the statistics of the given one.
+    fn evolve(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics);
+
+    // Objectives of the individuals a multi-objective optimizer currently
+    // breeds from, grouped into Pareto fronts (best first); `None` for
+    // single-objective ones.
+    fn pareto_fronts(&self) -> Option<Vec<Vec<Vec<f32>>>> {
+        None
+    }
+
+    // Hooks `observer` into the breeding of optimizers that report it (i.e.
+    // genetic algorithms); returns whether it was attached.
+    fn add_observer(&self, _observer: Box<dyn EvolutionObserver>) -> bool {
+        false
+    }
+}
+
+impl<S, C, M, I> Optimizer<I> for GeneticAlgorithm<S, C, M>
+where
+    S: SelectionMethod,
+    C: CrossoverMethod,
+    M: MutationMethod,
+    I: Individual,
+{
+    fn evolve(&self, rng: &mut dyn RngCore, population: &[I]) -> (Vec<I>, Statistics) {
+        GeneticAlgorithm::evolve(self, rng, population)
+    }
+
+    fn add_observer(&self, observer: Box<dyn EvolutionObserver>) -> bool {
+        self.observers.lock().unwrap().push(observer);
+        true
+    }
+}
@@ -0,0 +1,357 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RestartStrategy {
+    // Keeps the `keep` fittest individuals and replaces the rest with random
+    // chromosomes, whose genes are drawn uniformly from
+    // [-gene_range, gene_range].
+    Reseed {
+        keep: usize,
+        gene_range: f32,
+    },
+    // Mutates every child `multiplier` times instead of once for
+    // `generations` generations, starting with the stagnant one.
+    Hypermutation {
+        multiplier: usize,
+        generations: usize,
+    },
+}
+
+// When to shake up a population that got stuck, and how.
+//
+// A restart is triggered once max fitness hasn't improved for
+// `stagnation_window` generations in a row, or once the population's mean
+// pairwise distance drops below `min_diversity` - whichever is configured; at
+// least one of them has to be.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RestartPolicyRaw")
+)]
+pub struct RestartPolicy {
+    strategy: RestartStrategy,
+    stagnation_window: Option<usize>,
+    min_diversity: Option<f32>,
+}
+
+#[derive(Debug)]
+pub(crate) struct RestartState {
+    best_fitness: f32,
+    stagnant_generations: usize,
+    hypermutation_left: usize,
+    restarts: usize,
+}
+
+impl RestartPolicy {
+    pub fn new(strategy: RestartStrategy) -> Self {
+        check_strategy(strategy).unwrap_or_else(|err| panic!("{}", err));
+
+        Self {
+            strategy,
+            stagnation_window: None,
+            min_diversity: None,
+        }
+    }
+
+    pub fn with_stagnation_window(mut self, stagnation_window: usize) -> Self {
+        assert!(stagnation_window > 0);
+
+        self.stagnation_window = Some(stagnation_window);
+        self
+    }
+
+    pub fn with_min_diversity(mut self, min_diversity: f32) -> Self {
+        check_min_diversity(min_diversity).unwrap_or_else(|err| panic!("{}", err));
+
+        self.min_diversity = Some(min_diversity);
+        self
+    }
+
+    pub fn strategy(&self) -> RestartStrategy {
+        self.strategy
+    }
+
+    // Fails unless a trigger is configured.
+    pub(crate) fn check_trigger(&self) -> Result<(), Error> {
+        if self.stagnation_window.is_none() && self.min_diversity.is_none() {
+            return Err(Error::MissingRestartTrigger);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn needs_diversity(&self) -> bool {
+        self.min_diversity.is_some()
+    }
+}
+
+impl RestartState {
+    pub(crate) fn new() -> Self {
+        Self {
+            best_fitness: f32::NEG_INFINITY,
+            stagnant_generations: 0,
+            hypermutation_left: 0,
+            restarts: 0,
+        }
+    }
+
+    pub(crate) fn restarts(&self) -> usize {
+        self.restarts
+    }
+
+    // How many times the mutation method should be applied to every child.
+    pub(crate) fn mutation_passes(&self, policy: Option<&RestartPolicy>) -> usize {
+        match policy.map(|policy| policy.strategy) {
+            Some(RestartStrategy::Hypermutation { multiplier, .. })
+                if self.hypermutation_left > 0 =>
+            {
+                multiplier
+            }
+            _ => 1,
+        }
+    }
+
+    // Called once per generation, before breeding; returns whether the
+    // population should be restarted.
+    pub(crate) fn update(
+        &mut self,
+        policy: &RestartPolicy,
+        stats: &Statistics,
+        diversity: Option<f32>,
+    ) -> bool {
+        self.hypermutation_left = self.hypermutation_left.saturating_sub(1);
+
+        if stats.max_fitness() > self.best_fitness {
+            self.best_fitness = stats.max_fitness();
+            self.stagnant_generations = 0;
+        } else {
+            self.stagnant_generations += 1;
+        }
+
+        // Give an ongoing hypermutation a full window once it's over.
+        if self.hypermutation_left > 0 {
+            self.stagnant_generations = 0;
+            return false;
+        }
+
+        let stagnated = policy
+            .stagnation_window
+            .is_some_and(|window| self.stagnant_generations >= window);
+
+        let converged = policy
+            .min_diversity
+            .zip(diversity)
+            .is_some_and(|(min_diversity, diversity)| diversity < min_diversity);
+
+        if !stagnated && !converged {
+            return false;
+        }
+
+        self.stagnant_generations = 0;
+        self.restarts += 1;
+
+        if let RestartStrategy::Hypermutation { generations, .. } = policy.strategy {
+            self.hypermutation_left = generations;
+        }
+
+        true
+    }
+}
+
+// Random replacement for `chromosome`, keeping its mutation strategy.
+pub(crate) fn random_chromosome(
+    rng: &mut dyn RngCore,
+    chromosome: &Chromosome,
+    gene_range: f32,
+) -> Chromosome {
+    let genes = (0..chromosome.len())
+        .map(|_| rng.gen_range(-gene_range..=gene_range))
+        .collect();
+
+    Chromosome::new(genes).with_strategy(chromosome.strategy().clone())
+}
+
+// Deserialized as-is, then checked like in `new()`, `with_stagnation_window()`,
+// `with_min_diversity()` and `GeneticAlgorithm::with_restart_policy()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RestartPolicyRaw {
+    strategy: RestartStrategy,
+    stagnation_window: Option<usize>,
+    min_diversity: Option<f32>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RestartPolicyRaw> for RestartPolicy {
+    type Error = Error;
+
+    fn try_from(raw: RestartPolicyRaw) -> Result<Self, Error> {
+        check_strategy(raw.strategy)?;
+
+        if let Some(stagnation_window) = raw.stagnation_window {
+            check_parameter(
+                "stagnation window",
+                stagnation_window as f32,
+                stagnation_window > 0,
+            )?;
+        }
+
+        if let Some(min_diversity) = raw.min_diversity {
+            check_min_diversity(min_diversity)?;
+        }
+
+        let policy = Self {
+            strategy: raw.strategy,
+            stagnation_window: raw.stagnation_window,
+            min_diversity: raw.min_diversity,
+        };
+
+        policy.check_trigger()?;
+
+        Ok(policy)
+    }
+}
+
+fn check_min_diversity(min_diversity: f32) -> Result<(), Error> {
+    check_parameter("min diversity", min_diversity, min_diversity >= 0.0)
+}
+
+fn check_strategy(strategy: RestartStrategy) -> Result<(), Error> {
+    match strategy {
+        RestartStrategy::Reseed { gene_range, .. } => {
+            check_parameter("gene range", gene_range, gene_range >= 0.0)
+        }
+        RestartStrategy::Hypermutation {
+            multiplier,
+            generations,
+        } => {
+            check_parameter(
+                "hypermutation multiplier",
+                multiplier as f32,
+                multiplier > 0,
+            )?;
+            check_parameter(
+                "hypermutation generations",
+                generations as f32,
+                generations > 0,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(max_fitness: f32) -> Statistics {
+        Statistics::new(&[TestIndividual::new(0.0), TestIndividual::new(max_fitness)])
+    }
+
+    #[test]
+    fn test_stagnation() {
+        let policy = RestartPolicy::new(RestartStrategy::Reseed {
+            keep: 1,
+            gene_range: 1.0,
+        })
+        .with_stagnation_window(2);
+
+        let mut state = RestartState::new();
+
+        let restarts: Vec<bool> = [1.0, 2.0, 2.0, 1.0, 2.0, 2.0, 3.0]
+            .iter()
+            .map(|&max_fitness| state.update(&policy, &stats(max_fitness), None))
+            .collect();
+
+        assert_eq!(
+            restarts,
+            vec![false, false, false, true, false, true, false]
+        );
+        assert_eq!(state.restarts(), 2);
+        assert_eq!(state.mutation_passes(Some(&policy)), 1);
+    }
+
+    #[test]
+    fn test_min_diversity() {
+        let policy = RestartPolicy::new(RestartStrategy::Reseed {
+            keep: 1,
+            gene_range: 1.0,
+        })
+        .with_min_diversity(0.5);
+
+        let mut state = RestartState::new();
+
+        assert!(!state.update(&policy, &stats(1.0), Some(0.75)));
+        assert!(state.update(&policy, &stats(2.0), Some(0.25)));
+    }
+
+    #[test]
+    fn test_hypermutation() {
+        let policy = RestartPolicy::new(RestartStrategy::Hypermutation {
+            multiplier: 5,
+            generations: 2,
+        })
+        .with_stagnation_window(2);
+
+        let mut state = RestartState::new();
+
+        let passes: Vec<usize> = [1.0; 7]
+            .iter()
+            .map(|&max_fitness| {
+                state.update(&policy, &stats(max_fitness), None);
+                state.mutation_passes(Some(&policy))
+            })
+            .collect();
+
+        assert_eq!(passes, vec![1, 1, 5, 5, 1, 5, 5]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialization() {
+        let policy = RestartPolicy::new(RestartStrategy::Hypermutation {
+            multiplier: 2,
+            generations: 3,
+        })
+        .with_stagnation_window(5);
+
+        let json = serde_json::to_string(&policy).unwrap();
+        let policy: RestartPolicy = serde_json::from_str(&json).unwrap();
+        assert_eq!(policy.stagnation_window, Some(5));
+
+        let err = serde_json::from_str::<RestartPolicy>(
+            &json.replace(r#""multiplier":2"#, r#""multiplier":0"#),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid hypermutation multiplier"));
+
+        let err = serde_json::from_str::<RestartPolicy>(
+            &json.replace(r#""stagnation_window":5"#, r#""stagnation_window":0"#),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid stagnation window"));
+
+        let err = serde_json::from_str::<RestartPolicy>(
+            &json.replace(r#""stagnation_window":5"#, r#""stagnation_window":null"#),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("no trigger"));
+
+        let err = serde_json::from_str::<RestartPolicy>(
+            &json.replace(r#""min_diversity":null"#, r#""min_diversity":-1.0"#),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("invalid min diversity"));
+    }
+
+    #[test]
+    #[should_panic(expected = "got an invalid min diversity: NaN")]
+    fn test_nan_min_diversity() {
+        let _ = RestartPolicy::new(RestartStrategy::Reseed {
+            keep: 1,
+            gene_range: 1.0,
+        })
+        .with_min_diversity(f32::NAN);
+    }
+}
@@ -0,0 +1,263 @@
+use crate::*;
+use std::time::{Duration, Instant};
+
+// Keeps running generations until one of the configured stop conditions is
+// met, recording each generation's statistics along the way.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RunControllerRaw")
+)]
+pub struct RunController {
+    max_generations: Option<usize>,
+    target_fitness: Option<f32>,
+    stagnation_window: Option<usize>,
+    max_duration: Option<Duration>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StopReason {
+    MaxGenerations,
+    TargetFitness,
+    Stagnation,
+    Timeout,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunSummary {
+    history: Vec<Statistics>,
+    stop_reason: StopReason,
+    elapsed: Option<Duration>,
+}
+
+impl RunController {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_generations(mut self, max_generations: usize) -> Self {
+        self.max_generations = Some(max_generations);
+        self
+    }
+
+    // Stops once a generation's max fitness reaches `target_fitness`.
+    pub fn with_target_fitness(mut self, target_fitness: f32) -> Self {
+        self.target_fitness = Some(target_fitness);
+        self
+    }
+
+    // Stops after `stagnation_window` generations in a row without improving
+    // on the best max fitness seen so far.
+    pub fn with_stagnation_window(mut self, stagnation_window: usize) -> Self {
+        assert!(stagnation_window > 0);
+
+        self.stagnation_window = Some(stagnation_window);
+        self
+    }
+
+    // Stops once the run has taken at least `max_duration`; checked only
+    // between generations. The clock is read only when this is set, so it
+    // mustn't be used on wasm32-unknown-unknown, which has no clock.
+    pub fn with_max_duration(mut self, max_duration: Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+
+    // `generation` runs a single generation and returns its statistics.
+    pub fn run(&self, mut generation: impl FnMut() -> Statistics) -> RunSummary {
+        assert!(
+            self.max_generations.is_some()
+                || self.target_fitness.is_some()
+                || self.stagnation_window.is_some()
+                || self.max_duration.is_some(),
+            "got no stop condition"
+        );
+
+        let started_at = self.max_duration.map(|_| Instant::now());
+        let mut history = Vec::new();
+        let mut best_fitness = f32::NEG_INFINITY;
+        let mut stagnant_generations = 0;
+
+        loop {
+            let stats = generation();
+
+            if stats.max_fitness() > best_fitness {
+                best_fitness = stats.max_fitness();
+                stagnant_generations = 0;
+            } else {
+                stagnant_generations += 1;
+            }
+
+            history.push(stats);
+
+            let stop_reason = if self
+                .target_fitness
+                .is_some_and(|target_fitness| best_fitness >= target_fitness)
+            {
+                Some(StopReason::TargetFitness)
+            } else if self
+                .stagnation_window
+                .is_some_and(|window| stagnant_generations >= window)
+            {
+                Some(StopReason::Stagnation)
+            } else if self
+                .max_generations
+                .is_some_and(|max_generations| history.len() >= max_generations)
+            {
+                Some(StopReason::MaxGenerations)
+            } else if self
+                .max_duration
+                .zip(started_at)
+                .is_some_and(|(max_duration, started_at)| started_at.elapsed() >= max_duration)
+            {
+                Some(StopReason::Timeout)
+            } else {
+                None
+            };
+
+            if let Some(stop_reason) = stop_reason {
+                return RunSummary {
+                    history,
+                    stop_reason,
+                    elapsed: started_at.map(|started_at| started_at.elapsed()),
+                };
+            }
+        }
+    }
+}
+
+impl RunSummary {
+    // Statistics of every generation, oldest first.
+    pub fn history(&self) -> &[Statistics] {
+        &self.history
+    }
+
+    pub fn stop_reason(&self) -> StopReason {
+        self.stop_reason
+    }
+
+    pub fn generations(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn best_fitness(&self) -> f32 {
+        self.history
+            .iter()
+            .map(|stats| stats.max_fitness())
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    // Wall-clock time of the run; only measured when `max_duration` is set.
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.elapsed
+    }
+}
+
+// Deserialized as-is, then checked like in `with_stagnation_window()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RunControllerRaw {
+    max_generations: Option<usize>,
+    target_fitness: Option<f32>,
+    stagnation_window: Option<usize>,
+    max_duration: Option<Duration>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RunControllerRaw> for RunController {
+    type Error = Error;
+
+    fn try_from(raw: RunControllerRaw) -> Result<Self, Error> {
+        if let Some(stagnation_window) = raw.stagnation_window {
+            check_parameter(
+                "stagnation window",
+                stagnation_window as f32,
+                stagnation_window > 0,
+            )?;
+        }
+
+        Ok(Self {
+            max_generations: raw.max_generations,
+            target_fitness: raw.target_fitness,
+            stagnation_window: raw.stagnation_window,
+            max_duration: raw.max_duration,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Runs generations whose max fitness follows `max_fitness`, repeating its
+    // last value forever.
+    fn run(controller: RunController, max_fitness: &[f32]) -> RunSummary {
+        let mut generation = 0;
+
+        controller.run(|| {
+            let fitness = max_fitness[generation.min(max_fitness.len() - 1)];
+            generation += 1;
+
+            Statistics::new(&[TestIndividual::new(0.0), TestIndividual::new(fitness)])
+        })
+    }
+
+    #[test]
+    fn test_max_generations() {
+        let summary = run(RunController::new().with_max_generations(5), &[1.0, 2.0]);
+
+        assert_eq!(summary.stop_reason(), StopReason::MaxGenerations);
+        assert_eq!(summary.generations(), 5);
+        assert_eq!(summary.best_fitness(), 2.0);
+        assert_eq!(summary.elapsed(), None);
+    }
+
+    #[test]
+    fn test_target_fitness() {
+        let summary = run(
+            RunController::new()
+                .with_max_generations(10)
+                .with_target_fitness(3.0),
+            &[1.0, 2.0, 3.0, 4.0],
+        );
+
+        assert_eq!(summary.stop_reason(), StopReason::TargetFitness);
+        assert_eq!(summary.generations(), 3);
+        assert_eq!(summary.history()[2].max_fitness(), 3.0);
+    }
+
+    #[test]
+    fn test_stagnation() {
+        let summary = run(
+            RunController::new()
+                .with_max_generations(100)
+                .with_stagnation_window(3),
+            &[1.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+        );
+
+        // the best fitness (3.0) is reached in the 5th generation
+        assert_eq!(summary.stop_reason(), StopReason::Stagnation);
+        assert_eq!(summary.generations(), 8);
+    }
+
+    #[test]
+    fn test_max_duration() {
+        let summary = run(
+            RunController::new().with_max_duration(Duration::ZERO),
+            &[1.0],
+        );
+
+        assert_eq!(summary.stop_reason(), StopReason::Timeout);
+        assert_eq!(summary.generations(), 1);
+        assert!(summary.elapsed().is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "got no stop condition")]
+    fn test_no_stop_condition() {
+        run(RunController::new(), &[1.0]);
+    }
+}
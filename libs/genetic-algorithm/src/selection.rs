@@ -1,11 +1,62 @@
-pub use self::roulette_wheel::*;
+pub use self::{
+    fitness_sharing::*, rank::*, roulette_wheel::*, stochastic_universal::*, tournament::*,
+};
 
 use crate::*;
 
+mod fitness_sharing;
+mod rank;
 mod roulette_wheel;
+mod stochastic_universal;
+mod tournament;
+
+// Picks one individual at a time; see `SelectionMethod::selector()`.
+pub type Selector<'a, I> = Box<dyn FnMut(&mut dyn RngCore) -> &'a I + 'a>;
 
 pub trait SelectionMethod {
     fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
     where
         I: Individual;
+
+    // Checks that individuals can be selected from the population at all;
+    // used by `GeneticAlgorithm::try_evolve()`.
+    fn validate<I>(&self, population: &[I]) -> Result<(), Error>
+    where
+        I: Individual,
+    {
+        check_fitness(population)
+    }
+
+    // Whether `GeneticAlgorithm` should pick a whole generation's parents
+    // with one `select_many()` call, e.g. because the method needs to see the
+    // whole batch or precomputes something costly; otherwise parents are
+    // picked pair by pair with `select()`, interleaved with breeding.
+    fn prefers_batches(&self) -> bool {
+        false
+    }
+
+    // Picks individuals one by one from the same population, as
+    // `GeneticAlgorithm` does when interleaving selection with breeding;
+    // methods that have to prepare the population first (e.g. boxed ones)
+    // override this to do it once rather than on every pick.
+    fn selector<'a, I>(&'a self, population: &'a [I]) -> Selector<'a, I>
+    where
+        I: Individual,
+    {
+        Box::new(move |rng| self.select(rng, population))
+    }
+
+    // Selects `count` individuals at once; methods that need to see the whole
+    // batch (e.g. stochastic universal sampling) override this.
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        (0..count).map(|_| self.select(rng, population)).collect()
+    }
 }
@@ -0,0 +1,260 @@
+use crate::*;
+
+// Wraps another selection method, dividing each individual's fitness by its
+// niche count - the number of neighbours within `radius` in gene space,
+// weighted by how close they are - so that crowded regions of the search space
+// become less attractive and several niches can survive side by side.
+//
+// Assumes non-negative fitness, as dividing a negative fitness would reward
+// crowding instead.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "FitnessSharingRaw<S>")
+)]
+pub struct FitnessSharing<S> {
+    selection_method: S,
+    radius: f32,
+    alpha: f32,
+}
+
+impl<S> FitnessSharing<S> {
+    // radius: Distance in gene space beyond which individuals don't share fitness.
+    // alpha: Shape of the sharing function; 1.0 decreases linearly with distance.
+    pub fn new(selection_method: S, radius: f32, alpha: f32) -> Self {
+        Self::try_new(selection_method, radius, alpha).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(selection_method: S, radius: f32, alpha: f32) -> Result<Self, Error> {
+        check_parameter("sharing radius", radius, radius > 0.0)?;
+        check_parameter("sharing alpha", alpha, alpha > 0.0)?;
+
+        Ok(Self {
+            selection_method,
+            radius,
+            alpha,
+        })
+    }
+
+    fn shared<'a, I>(&self, population: &'a [I]) -> Vec<SharedIndividual<'a>>
+    where
+        I: Individual,
+    {
+        population
+            .iter()
+            .zip(self.shared_fitness(population))
+            .map(|(individual, fitness)| SharedIndividual {
+                chromosome: individual.to_chromosome(),
+                fitness,
+            })
+            .collect()
+    }
+
+    pub(crate) fn shared_fitness<I>(&self, population: &[I]) -> Vec<f32>
+    where
+        I: Individual,
+    {
+        population
+            .iter()
+            .map(|individual| {
+                let niche_count: f32 = population
+                    .iter()
+                    .map(|neighbour| {
+                        let distance = diversity::distance(
+                            individual.to_chromosome().genes(),
+                            neighbour.to_chromosome().genes(),
+                        );
+
+                        if distance < self.radius {
+                            1.0 - (distance / self.radius).powf(self.alpha)
+                        } else {
+                            0.0
+                        }
+                    })
+                    .sum();
+
+                // niche_count >= 1.0, since everyone is their own neighbour
+                individual.fitness() / niche_count
+            })
+            .collect()
+    }
+}
+
+impl<S> SelectionMethod for FitnessSharing<S>
+where
+    S: SelectionMethod,
+{
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    fn prefers_batches(&self) -> bool {
+        true
+    }
+
+    // The wrapped method picks from the shared fitness, so that's what it has
+    // to be able to handle.
+    fn validate<I>(&self, population: &[I]) -> Result<(), Error>
+    where
+        I: Individual,
+    {
+        check_fitness(population)?;
+        check_lengths(
+            population
+                .iter()
+                .map(|individual| individual.to_chromosome()),
+        )?;
+
+        self.selection_method.validate(&self.shared(population))
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        let shared = self.shared(population);
+
+        self.selection_method
+            .select_many(rng, &shared, count)
+            .into_iter()
+            .map(|individual| &population[index_of(&shared, individual)])
+            .collect()
+    }
+}
+
+// Presents an individual's chromosome to a selection method with another
+// fitness - e.g. its shared one - or without the individual's concrete type, as
+// `DynSelectionMethod` needs.
+pub struct SharedIndividual<'a> {
+    pub(crate) chromosome: &'a Chromosome,
+    pub(crate) fitness: f32,
+}
+
+impl Individual for SharedIndividual<'_> {
+    fn from_chromosome(_: Chromosome) -> Self {
+        unreachable!("selection methods don't create individuals")
+    }
+
+    fn fitness(&self) -> f32 {
+        self.fitness
+    }
+
+    fn to_chromosome(&self) -> &Chromosome {
+        self.chromosome
+    }
+}
+
+// Deserialized as-is, then checked by `try_new()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct FitnessSharingRaw<S> {
+    selection_method: S,
+    radius: f32,
+    alpha: f32,
+}
+
+#[cfg(feature = "serde")]
+impl<S> TryFrom<FitnessSharingRaw<S>> for FitnessSharing<S> {
+    type Error = Error;
+
+    fn try_from(raw: FitnessSharingRaw<S>) -> Result<Self, Error> {
+        Self::try_new(raw.selection_method, raw.radius, raw.alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::BTreeMap;
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::with_genes(vec![3.0, 3.0]), // fitness = 6.0
+            TestIndividual::with_genes(vec![3.0, 3.0]), // fitness = 6.0
+            TestIndividual::with_genes(vec![3.0, 3.0]), // fitness = 6.0
+            TestIndividual::with_genes(vec![2.0, 3.0]), // fitness = 5.0
+            TestIndividual::with_genes(vec![0.0, 4.0]), // fitness = 4.0
+        ]
+    }
+
+    #[test]
+    fn test_shared_fitness() {
+        let selection_method = FitnessSharing::new(RouletteWheelSelection::new(), 2.0, 1.0);
+
+        let actual = selection_method.shared_fitness(&population());
+        let expected = [6.0 / 3.5, 6.0 / 3.5, 6.0 / 3.5, 5.0 / 2.5, 4.0];
+
+        assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test() {
+        let selection_method = FitnessSharing::new(RouletteWheelSelection::new(), 2.0, 1.0);
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = population();
+
+        let mut actual = BTreeMap::new();
+        for individual in selection_method.select_many(&mut rng, &population, 1000) {
+            *actual.entry(individual.fitness() as i32).or_insert(0) += 1;
+        }
+
+        let expected = maplit::btreemap! {
+            4 => 347,
+            5 => 182,
+            6 => 471,
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_validate() {
+        let selection_method = FitnessSharing::new(RouletteWheelSelection::new(), 2.0, 1.0);
+
+        assert_eq!(
+            selection_method.validate(&[
+                TestIndividual::with_genes(vec![0.0, 0.0]),
+                TestIndividual::with_genes(vec![0.0, 0.0]),
+            ]),
+            Err(Error::ZeroTotalFitness)
+        );
+
+        assert_eq!(
+            selection_method.validate(&[
+                TestIndividual::with_genes(vec![1.0, 1.0]),
+                TestIndividual::with_genes(vec![1.0]),
+            ]),
+            Err(Error::MismatchedChromosomeLengths {
+                expected: 2,
+                actual: 1
+            })
+        );
+
+        assert_eq!(selection_method.validate(&population()), Ok(()));
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(FitnessSharing::try_new(RouletteWheelSelection::new(), 2.0, 1.0).is_ok());
+
+        assert_eq!(
+            FitnessSharing::try_new(RouletteWheelSelection::new(), 0.0, 1.0).err(),
+            Some(Error::InvalidParameter {
+                name: "sharing radius",
+                value: 0.0
+            })
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::*;
+use rand::distributions::{Distribution, WeightedIndex};
+
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RankSelectionRaw")
+)]
+pub struct RankSelection {
+    scaling: RankScaling,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum RankScaling {
+    Linear { pressure: f32 },
+    Exponential { base: f32 },
+}
+
+impl RankSelection {
+    // pressure: Expected number of times the fittest individual gets selected
+    //           per population-sized batch, from 1.0 (uniform) to 2.0.
+    pub fn linear(pressure: f32) -> Self {
+        Self::try_linear(pressure).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_linear(pressure: f32) -> Result<Self, Error> {
+        check_parameter(
+            "selection pressure",
+            pressure,
+            (1.0..=2.0).contains(&pressure),
+        )?;
+
+        Ok(Self {
+            scaling: RankScaling::Linear { pressure },
+        })
+    }
+
+    // base: Weight ratio between neighbouring ranks, from 0.0 (always pick the
+    //       fittest) to 1.0 (uniform).
+    pub fn exponential(base: f32) -> Self {
+        Self::try_exponential(base).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_exponential(base: f32) -> Result<Self, Error> {
+        check_parameter("rank base", base, base > 0.0 && base <= 1.0)?;
+
+        Ok(Self {
+            scaling: RankScaling::Exponential { base },
+        })
+    }
+
+    // Returns the population sorted from the least to the most fit individual,
+    // together with the selection weight of each rank.
+    fn ranked<'a, I>(&self, population: &'a [I]) -> (Vec<&'a I>, WeightedIndex<f32>)
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+
+        let mut ranked: Vec<&I> = population.iter().collect();
+        ranked.sort_by(|a, b| a.fitness().total_cmp(&b.fitness()));
+
+        let n = ranked.len();
+        let weights = (0..n).map(|rank| match self.scaling {
+            RankScaling::Linear { pressure } => {
+                if n == 1 {
+                    1.0
+                } else {
+                    (2.0 - pressure) + 2.0 * (pressure - 1.0) * rank as f32 / (n - 1) as f32
+                }
+            }
+            RankScaling::Exponential { base } => base.powi((n - 1 - rank) as i32),
+        });
+
+        let weights = WeightedIndex::new(weights).expect("got an empty population");
+
+        (ranked, weights)
+    }
+}
+
+impl SelectionMethod for RankSelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        let (ranked, weights) = self.ranked(population);
+
+        ranked[weights.sample(rng)]
+    }
+
+    fn prefers_batches(&self) -> bool {
+        true
+    }
+
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        let (ranked, weights) = self.ranked(population);
+
+        (0..count).map(|_| ranked[weights.sample(rng)]).collect()
+    }
+}
+
+// Deserialized as-is, then checked by `try_linear()` / `try_exponential()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RankSelectionRaw {
+    scaling: RankScaling,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RankSelectionRaw> for RankSelection {
+    type Error = Error;
+
+    fn try_from(raw: RankSelectionRaw) -> Result<Self, Error> {
+        match raw.scaling {
+            RankScaling::Linear { pressure } => Self::try_linear(pressure),
+            RankScaling::Exponential { base } => Self::try_exponential(base),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::BTreeMap;
+
+    fn actual(selection_method: RankSelection) -> BTreeMap<i32, usize> {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let population = vec![
+            TestIndividual::new(20.0),
+            TestIndividual::new(-1.0),
+            TestIndividual::new(400.0),
+            TestIndividual::new(0.0),
+        ];
+
+        let mut actual = BTreeMap::new();
+        for individual in selection_method.select_many(&mut rng, &population, 1000) {
+            *actual.entry(individual.fitness() as i32).or_insert(0) += 1;
+        }
+
+        actual
+    }
+
+    #[test]
+    fn test_linear() {
+        let expected = maplit::btreemap! {
+            -1 => 134,
+            0 => 193,
+            20 => 310,
+            400 => 363,
+        };
+
+        assert_eq!(actual(RankSelection::linear(1.5)), expected);
+    }
+
+    #[test]
+    fn test_exponential() {
+        let expected = maplit::btreemap! {
+            -1 => 72,
+            0 => 130,
+            20 => 273,
+            400 => 525,
+        };
+
+        assert_eq!(actual(RankSelection::exponential(0.5)), expected);
+    }
+}
@@ -1,11 +1,33 @@
 use crate::*;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RouletteWheelSelection;
 
 impl RouletteWheelSelection {
     pub fn new() -> Self {
         Self
     }
+
+    pub fn try_select<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+    ) -> Result<&'a I, Error>
+    where
+        I: Individual,
+    {
+        self.validate(population)?;
+
+        Ok(population
+            .choose_weighted(rng, |individual| individual.fitness())
+            .expect("population has been validated"))
+    }
+}
+
+impl Default for RouletteWheelSelection {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl SelectionMethod for RouletteWheelSelection {
@@ -13,9 +35,17 @@ impl SelectionMethod for RouletteWheelSelection {
     where
         I: Individual,
     {
-        population
-            .choose_weighted(rng, |individual| individual.fitness())
-            .expect("got an empty population")
+        self.try_select(rng, population)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    // Fitness is used as a weight, so it has to be finite and non-negative,
+    // and not zero for everyone.
+    fn validate<I>(&self, population: &[I]) -> Result<(), Error>
+    where
+        I: Individual,
+    {
+        check_weights(population)
     }
 }
 
@@ -54,4 +84,45 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_try_select() {
+        let selection_method = RouletteWheelSelection::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let cases = [
+            (vec![], Error::EmptyPopulation),
+            (
+                vec![TestIndividual::new(1.0), TestIndividual::new(f32::NAN)],
+                Error::NanFitness { index: 1 },
+            ),
+            (
+                vec![TestIndividual::new(1.0), TestIndividual::new(-1.0)],
+                Error::InvalidFitness {
+                    index: 1,
+                    fitness: -1.0,
+                },
+            ),
+            (
+                vec![TestIndividual::new(0.0), TestIndividual::new(0.0)],
+                Error::ZeroTotalFitness,
+            ),
+        ];
+
+        for (population, expected) in cases {
+            assert_eq!(
+                selection_method
+                    .try_select(&mut rng, &population)
+                    .unwrap_err(),
+                expected
+            );
+        }
+
+        let population = vec![TestIndividual::new(0.0), TestIndividual::new(1.0)];
+
+        assert_eq!(
+            selection_method.try_select(&mut rng, &population),
+            Ok(&population[1])
+        );
+    }
 }
@@ -0,0 +1,179 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochasticUniversalSampling;
+
+impl StochasticUniversalSampling {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for StochasticUniversalSampling {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SelectionMethod for StochasticUniversalSampling {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        self.select_many(rng, population, 1)[0]
+    }
+
+    fn prefers_batches(&self) -> bool {
+        true
+    }
+
+    // Like for the roulette wheel, fitness is used as a weight.
+    fn validate<I>(&self, population: &[I]) -> Result<(), Error>
+    where
+        I: Individual,
+    {
+        check_weights(population)
+    }
+
+    // Places `count` evenly spaced pointers on the roulette wheel and spins it
+    // once, so every individual is picked within one of its expected count.
+    fn select_many<'a, I>(
+        &self,
+        rng: &mut dyn RngCore,
+        population: &'a [I],
+        count: usize,
+    ) -> Vec<&'a I>
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+        assert!(population
+            .iter()
+            .all(|individual| individual.fitness() >= 0.0));
+
+        let total_fitness: f32 = population
+            .iter()
+            .map(|individual| individual.fitness())
+            .sum();
+        assert!(total_fitness > 0.0);
+
+        if count == 0 {
+            return Vec::new();
+        }
+
+        let spacing = total_fitness / count as f32;
+        let mut pointer = rng.gen_range(0.0..spacing);
+
+        let mut selected = Vec::with_capacity(count);
+        let mut cumulative_fitness = 0.0;
+
+        for individual in population {
+            cumulative_fitness += individual.fitness();
+
+            while pointer < cumulative_fitness && selected.len() < count {
+                selected.push(individual);
+                pointer += spacing;
+            }
+        }
+
+        // Rounding errors can leave the last pointer just past the wheel, where
+        // it belongs to the last individual that can be picked at all.
+        if selected.len() < count {
+            let last = population
+                .iter()
+                .rfind(|individual| individual.fitness() > 0.0)
+                .unwrap();
+
+            selected.resize(count, last);
+        }
+
+        selected.shuffle(rng);
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::mock::StepRng;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test() {
+        let selection_method = StochasticUniversalSampling::new();
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let population = vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ];
+
+        let mut actual = BTreeMap::new();
+        for individual in selection_method.select_many(&mut rng, &population, 1000) {
+            *actual.entry(individual.fitness() as i32).or_insert(0) += 1;
+        }
+
+        let expected = maplit::btreemap! {
+            1 => 100,
+            2 => 200,
+            3 => 300,
+            4 => 400,
+        };
+
+        assert_eq!(actual, expected);
+    }
+
+    // Rounding errors can push the last pointer past the wheel, but never
+    // onto an individual that has no fitness.
+    #[test]
+    fn test_rounding() {
+        // Starts the pointers just short of `spacing`, as far in as they go.
+        let mut rng = StepRng::new(u32::MAX as u64, 1);
+
+        let mut population = vec![TestIndividual::new(1.0 / 3.0); 5];
+        population.push(TestIndividual::new(0.0));
+
+        assert!(StochasticUniversalSampling::new()
+            .select_many(&mut rng, &population, 3)
+            .iter()
+            .all(|individual| individual.fitness() > 0.0));
+    }
+
+    #[test]
+    fn test_no_count() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let population = vec![TestIndividual::new(1.0)];
+
+        assert!(StochasticUniversalSampling::new()
+            .select_many(&mut rng, &population, 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let selection_method = StochasticUniversalSampling::new();
+
+        assert_eq!(
+            selection_method.validate(&[TestIndividual::new(0.0), TestIndividual::new(0.0)]),
+            Err(Error::ZeroTotalFitness)
+        );
+
+        assert_eq!(
+            selection_method.validate(&[TestIndividual::new(1.0), TestIndividual::new(-1.0)]),
+            Err(Error::InvalidFitness {
+                index: 1,
+                fitness: -1.0
+            })
+        );
+
+        assert_eq!(
+            selection_method.validate(&[TestIndividual::new(0.0), TestIndividual::new(1.0)]),
+            Ok(())
+        );
+    }
+}
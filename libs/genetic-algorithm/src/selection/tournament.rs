@@ -0,0 +1,145 @@
+use crate::*;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "TournamentSelectionRaw")
+)]
+pub struct TournamentSelection {
+    size: usize,
+    chance: f32,
+}
+
+impl TournamentSelection {
+    // size: Number of individuals drawn (with replacement) into each tournament.
+    // chance: Probability that the fittest contestant wins; if it doesn't, the
+    //         runner-up wins with the same probability, and so on.
+    pub fn new(size: usize, chance: f32) -> Self {
+        Self::try_new(size, chance).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(size: usize, chance: f32) -> Result<Self, Error> {
+        check_parameter("tournament size", size as f32, size > 0)?;
+        check_parameter("tournament chance", chance, (0.0..=1.0).contains(&chance))?;
+
+        Ok(Self { size, chance })
+    }
+}
+
+impl SelectionMethod for TournamentSelection {
+    fn select<'a, I>(&self, rng: &mut dyn RngCore, population: &'a [I]) -> &'a I
+    where
+        I: Individual,
+    {
+        assert!(!population.is_empty());
+
+        let mut contestants: Vec<&I> = (0..self.size)
+            .map(|_| population.choose(rng).expect("got an empty population"))
+            .collect();
+
+        contestants.sort_by(|a, b| b.fitness().total_cmp(&a.fitness()));
+
+        let (last, rest) = contestants.split_last().unwrap();
+
+        for contestant in rest {
+            if rng.gen_bool(self.chance as _) {
+                return contestant;
+            }
+        }
+
+        last
+    }
+}
+
+// Deserialized as-is, then checked by `try_new()`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct TournamentSelectionRaw {
+    size: usize,
+    chance: f32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TournamentSelectionRaw> for TournamentSelection {
+    type Error = Error;
+
+    fn try_from(raw: TournamentSelectionRaw) -> Result<Self, Error> {
+        Self::try_new(raw.size, raw.chance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use std::collections::BTreeMap;
+
+    fn actual(size: usize, chance: f32, population: &[TestIndividual]) -> BTreeMap<i32, usize> {
+        let selection_method = TournamentSelection::new(size, chance);
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut actual = BTreeMap::new();
+        for _ in 0..1000 {
+            let fitness = selection_method.select(&mut rng, population).fitness() as i32;
+
+            *actual.entry(fitness).or_insert(0) += 1;
+        }
+
+        actual
+    }
+
+    #[test]
+    fn test() {
+        let population = vec![
+            TestIndividual::new(2.0),
+            TestIndividual::new(1.0),
+            TestIndividual::new(4.0),
+            TestIndividual::new(3.0),
+        ];
+
+        let expected = maplit::btreemap! {
+            1 => 66,
+            2 => 179,
+            3 => 299,
+            4 => 456,
+        };
+
+        assert_eq!(actual(2, 1.0, &population), expected);
+    }
+
+    #[test]
+    fn test_non_positive_fitness() {
+        let population = vec![
+            TestIndividual::new(0.0),
+            TestIndividual::new(-1.0),
+            TestIndividual::new(-4.0),
+            TestIndividual::new(0.0),
+        ];
+
+        let expected = maplit::btreemap! {
+            -4 => 68,
+            -1 => 164,
+            0 => 768,
+        };
+
+        assert_eq!(actual(3, 0.8, &population), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialization() {
+        let selection_method: TournamentSelection =
+            serde_json::from_str(r#"{"size":3,"chance":0.5}"#).unwrap();
+        assert_eq!(selection_method.size, 3);
+
+        let err =
+            serde_json::from_str::<TournamentSelection>(r#"{"size":0,"chance":0.5}"#).unwrap_err();
+        assert!(err.to_string().contains("invalid tournament size"));
+
+        let err =
+            serde_json::from_str::<TournamentSelection>(r#"{"size":2,"chance":1.5}"#).unwrap_err();
+        assert!(err.to_string().contains("invalid tournament chance"));
+    }
+}
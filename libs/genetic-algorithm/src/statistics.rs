@@ -1,26 +1,47 @@
 use crate::*;
+use std::fmt;
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Statistics {
+    population_size: usize,
+    best_index: usize,
     min_fitness: f32,
     max_fitness: f32,
     avg_fitness: f32,
+    median_fitness: f32,
+    std_dev_fitness: f32,
+    lower_quartile_fitness: f32,
+    upper_quartile_fitness: f32,
 }
 
 impl Statistics {
+    // Panics on an empty population or on NaN fitness; see `try_new()`.
     pub fn new<I>(population: &[I]) -> Self
     where
         I: Individual,
     {
-        assert!(!population.is_empty());
+        Self::try_new(population).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new<I>(population: &[I]) -> Result<Self, Error>
+    where
+        I: Individual,
+    {
+        check_fitness(population)?;
 
         let population_size = population.len();
+        let mut best_index = 0;
         let mut min_fitness = population[0].fitness();
         let mut max_fitness = population[0].fitness();
         let mut sum_fitness = 0.0;
 
-        for i in 0..population_size {
-            let fitness = population[i].fitness();
+        for (i, individual) in population.iter().enumerate() {
+            let fitness = individual.fitness();
+
+            if fitness > max_fitness {
+                best_index = i;
+            }
 
             min_fitness = min_fitness.min(fitness);
             max_fitness = max_fitness.max(fitness);
@@ -29,11 +50,39 @@ impl Statistics {
 
         let avg_fitness = sum_fitness / population_size as f32;
 
-        Self {
+        let variance = population
+            .iter()
+            .map(|individual| (individual.fitness() - avg_fitness).powi(2))
+            .sum::<f32>()
+            / population_size as f32;
+
+        let mut sorted_fitness: Vec<f32> = population
+            .iter()
+            .map(|individual| individual.fitness())
+            .collect();
+        sorted_fitness.sort_by(f32::total_cmp);
+
+        Ok(Self {
+            population_size,
+            best_index,
             min_fitness,
             max_fitness,
             avg_fitness,
-        }
+            median_fitness: percentile(&sorted_fitness, 0.5),
+            std_dev_fitness: variance.sqrt(),
+            lower_quartile_fitness: percentile(&sorted_fitness, 0.25),
+            upper_quartile_fitness: percentile(&sorted_fitness, 0.75),
+        })
+    }
+
+    pub fn population_size(&self) -> usize {
+        self.population_size
+    }
+
+    // Index (within the evaluated population) of the individual with the
+    // highest fitness; ties go to the first one.
+    pub fn best_index(&self) -> usize {
+        self.best_index
     }
 
     pub fn min_fitness(&self) -> f32 {
@@ -47,4 +96,130 @@ impl Statistics {
     pub fn avg_fitness(&self) -> f32 {
         self.avg_fitness
     }
+
+    pub fn median_fitness(&self) -> f32 {
+        self.median_fitness
+    }
+
+    pub fn std_dev_fitness(&self) -> f32 {
+        self.std_dev_fitness
+    }
+
+    pub fn lower_quartile_fitness(&self) -> f32 {
+        self.lower_quartile_fitness
+    }
+
+    pub fn upper_quartile_fitness(&self) -> f32 {
+        self.upper_quartile_fitness
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min={:.2}, max={:.2}, avg={:.2}, median={:.2}, std_dev={:.2}, q1={:.2}, q3={:.2}, best=#{}, size={}",
+            self.min_fitness,
+            self.max_fitness,
+            self.avg_fitness,
+            self.median_fitness,
+            self.std_dev_fitness,
+            self.lower_quartile_fitness,
+            self.upper_quartile_fitness,
+            self.best_index,
+            self.population_size,
+        )
+    }
+}
+
+// Linearly interpolates between the closest ranks of an ascending slice;
+// `p` ranges from 0.0 (minimum) to 1.0 (maximum).
+fn percentile(sorted: &[f32], p: f32) -> f32 {
+    let rank = p * (sorted.len() - 1) as f32;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn statistics() -> Statistics {
+        let population = vec![
+            TestIndividual::new(30.0),
+            TestIndividual::new(10.0),
+            TestIndividual::new(20.0),
+            TestIndividual::new(60.0),
+            TestIndividual::new(40.0),
+            TestIndividual::new(60.0),
+        ];
+
+        Statistics::new(&population)
+    }
+
+    #[test]
+    fn test() {
+        let stats = statistics();
+
+        assert_eq!(stats.population_size(), 6);
+        assert_eq!(stats.best_index(), 3);
+        assert_relative_eq!(stats.min_fitness(), 10.0);
+        assert_relative_eq!(stats.max_fitness(), 60.0);
+        assert_relative_eq!(stats.avg_fitness(), 36.666668);
+        assert_relative_eq!(stats.median_fitness(), 35.0);
+        assert_relative_eq!(stats.std_dev_fitness(), 18.85618);
+        assert_relative_eq!(stats.lower_quartile_fitness(), 22.5);
+        assert_relative_eq!(stats.upper_quartile_fitness(), 55.0);
+    }
+
+    #[test]
+    fn test_single_individual() {
+        let stats = Statistics::new(&[TestIndividual::new(5.0)]);
+
+        assert_eq!(stats.best_index(), 0);
+        assert_relative_eq!(stats.median_fitness(), 5.0);
+        assert_relative_eq!(stats.std_dev_fitness(), 0.0);
+        assert_relative_eq!(stats.lower_quartile_fitness(), 5.0);
+        assert_relative_eq!(stats.upper_quartile_fitness(), 5.0);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            statistics().to_string(),
+            "min=10.00, max=60.00, avg=36.67, median=35.00, std_dev=18.86, q1=22.50, q3=55.00, best=#3, size=6",
+        );
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            Statistics::try_new::<TestIndividual>(&[]).unwrap_err(),
+            Error::EmptyPopulation
+        );
+
+        assert_eq!(
+            Statistics::try_new(&[TestIndividual::new(1.0), TestIndividual::new(f32::NAN)])
+                .unwrap_err(),
+            Error::NanFitness { index: 1 }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "individual #0 has NaN fitness")]
+    fn test_nan_fitness() {
+        Statistics::new(&[TestIndividual::new(f32::NAN)]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialization() {
+        let json = serde_json::to_string(&statistics()).unwrap();
+        let actual: Statistics = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(actual.to_string(), statistics().to_string());
+    }
 }
@@ -0,0 +1,165 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReplacementStrategy {
+    // Replace the individuals with the lowest fitness.
+    Worst,
+    // Replace the individuals that have been in the population the longest.
+    Oldest,
+    // Replace individuals picked uniformly at random.
+    Random,
+}
+
+// Steady-state evolution: instead of replacing the whole population at once,
+// every step breeds a small batch of children and tells the caller which
+// members of the population they should replace.
+pub struct SteadyState<S, C, M> {
+    ga: GeneticAlgorithm<S, C, M>,
+    replacement: ReplacementStrategy,
+    batch_size: usize,
+    // Step at which each slot of the population was last replaced.
+    births: Vec<usize>,
+    step: usize,
+}
+
+impl<S, C, M> SteadyState<S, C, M>
+where
+    S: SelectionMethod,
+    C: CrossoverMethod,
+    M: MutationMethod,
+{
+    pub fn new(
+        ga: GeneticAlgorithm<S, C, M>,
+        replacement: ReplacementStrategy,
+        batch_size: usize,
+    ) -> Self {
+        assert!(batch_size > 0);
+
+        Self {
+            ga,
+            replacement,
+            batch_size,
+            births: Vec::new(),
+            step: 0,
+        }
+    }
+
+    pub fn step_count(&self) -> usize {
+        self.step
+    }
+
+    // Breeds `batch_size` children from the (evaluated) population, returning
+    // each one together with the index of the individual it should replace;
+    // indices are distinct.
+    pub fn step<I>(&mut self, rng: &mut dyn RngCore, population: &[I]) -> Vec<(usize, I)>
+    where
+        I: Individual,
+    {
+        assert!(self.batch_size <= population.len());
+
+        // Slots the population grew by since the last step count as newborns.
+        self.births.resize(population.len(), self.step);
+        self.step += 1;
+
+        let children = self.ga.breed(rng, population, self.batch_size);
+
+        let replaced: Vec<usize> = match self.replacement {
+            ReplacementStrategy::Worst => {
+                let mut ranked: Vec<usize> = (0..population.len()).collect();
+                ranked
+                    .sort_by(|&a, &b| population[a].fitness().total_cmp(&population[b].fitness()));
+                ranked.truncate(self.batch_size);
+                ranked
+            }
+            ReplacementStrategy::Oldest => {
+                let mut ranked: Vec<usize> = (0..population.len()).collect();
+                ranked.sort_by_key(|&i| self.births[i]);
+                ranked.truncate(self.batch_size);
+                ranked
+            }
+            ReplacementStrategy::Random => {
+                rand::seq::index::sample(rng, population.len(), self.batch_size).into_vec()
+            }
+        };
+
+        for &i in &replaced {
+            self.births[i] = self.step;
+        }
+
+        replaced.into_iter().zip(children).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    fn steady_state(
+        replacement: ReplacementStrategy,
+    ) -> SteadyState<TournamentSelection, UniformCrossover, UniformMutation> {
+        let ga = GeneticAlgorithm::new(
+            TournamentSelection::new(2, 1.0),
+            UniformCrossover::new(),
+            UniformMutation::new(0.5, 0.5),
+        );
+
+        SteadyState::new(ga, replacement, 2)
+    }
+
+    fn population() -> Vec<TestIndividual> {
+        vec![
+            TestIndividual::with_genes(vec![2.0, 1.0]), // fitness = 3.0
+            TestIndividual::with_genes(vec![0.0, 0.0]), // fitness = 0.0
+            TestIndividual::with_genes(vec![1.0, 4.0]), // fitness = 5.0
+            TestIndividual::with_genes(vec![1.0, 0.0]), // fitness = 1.0
+            TestIndividual::with_genes(vec![3.0, 3.0]), // fitness = 6.0
+        ]
+    }
+
+    fn replaced(replacements: &[(usize, TestIndividual)]) -> Vec<usize> {
+        replacements.iter().map(|(i, _)| *i).collect()
+    }
+
+    #[test]
+    fn test_worst() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut steady_state = steady_state(ReplacementStrategy::Worst);
+
+        let replacements = steady_state.step(&mut rng, &population());
+
+        assert_eq!(replaced(&replacements), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_oldest() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut steady_state = steady_state(ReplacementStrategy::Oldest);
+        let mut population = population();
+
+        let mut actual = Vec::new();
+        for _ in 0..4 {
+            let replacements = steady_state.step(&mut rng, &population);
+            actual.push(replaced(&replacements));
+
+            for (i, child) in replacements {
+                population[i] = child;
+            }
+        }
+
+        assert_eq!(actual, vec![vec![0, 1], vec![2, 3], vec![4, 0], vec![1, 2]]);
+        assert_eq!(steady_state.step_count(), 4);
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut steady_state = steady_state(ReplacementStrategy::Random);
+
+        let replacements = steady_state.step(&mut rng, &population());
+
+        assert_eq!(replaced(&replacements), vec![4, 3]);
+    }
+}
@@ -44,6 +44,17 @@ impl Network {
             .collect()
     }
 
+    // Number of weights (bias included) of every neuron, in the same order as
+    // they're laid out by `weights()`.
+    pub fn neuron_sizes(layer_info: &[usize]) -> Vec<usize> {
+        assert!(layer_info.len() > 1);
+
+        layer_info
+            .windows(2)
+            .flat_map(|layers| std::iter::repeat_n(layers[0] + 1, layers[1]))
+            .collect()
+    }
+
     pub fn from_weights(layer_info: &[usize], weights: Vec<f32>) -> Self {
         let mut expected_num_weights = 0;
         for i in 1..(layer_info.len()) {
@@ -144,6 +155,17 @@ mod tests {
         assert_relative_eq!(actual_weights.as_slice(), expected_weights.as_slice());
     }
 
+    #[test]
+    fn test_neuron_sizes() {
+        let layer_info = &[3, 2, 1];
+
+        let network = Network::random(&mut ChaCha8Rng::from_seed(Default::default()), layer_info);
+        let actual = Network::neuron_sizes(layer_info);
+
+        assert_eq!(actual, vec![4, 4, 3]);
+        assert_eq!(actual.iter().sum::<usize>(), network.weights().len());
+    }
+
     #[test]
     fn from_weights() {
         let layer_info = &[3, 2];
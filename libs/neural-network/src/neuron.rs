@@ -26,8 +26,8 @@ impl Neuron {
         assert_eq!(self.weights.len(), inputs.len());
 
         let mut output = 0.0;
-        for i in 0..inputs.len() {
-            output += inputs[i] * self.weights[i];
+        for (input, weight) in inputs.iter().zip(&self.weights) {
+            output += input * weight;
         }
 
         (output + self.bias).max(0.0)
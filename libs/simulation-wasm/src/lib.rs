@@ -8,6 +8,7 @@ use lib_simulation as sim;
 use rand::prelude::*;
 use serde::Serialize;
 use serde_wasm_bindgen::to_value;
+use sim::ga;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -36,14 +37,33 @@ impl Simulation {
         self.sim.step(&mut self.rng);
     }
 
+    // Operators are given by name, e.g. "tournament", "uniform", "gaussian".
+    pub fn set_operators(
+        &mut self,
+        selection: &str,
+        crossover: &str,
+        mutation: &str,
+    ) -> Result<(), JsValue> {
+        let to_js = |err: ga::Error| JsValue::from_str(&err.to_string());
+
+        self.sim
+            .set_operators(
+                &ga::SelectionConfig::from_name(selection).map_err(to_js)?,
+                &ga::CrossoverConfig::from_name(crossover).map_err(to_js)?,
+                &ga::MutationConfig::from_name(mutation).map_err(to_js)?,
+            )
+            .map_err(to_js)
+    }
+
     pub fn train(&mut self) -> String {
         let stats = self.sim.train(&mut self.rng);
 
-        format!(
-            "min={:.2}, max={:.2}, avg={:.2}",
-            stats.min_fitness(),
-            stats.max_fitness(),
-            stats.avg_fitness()
-        )
+        stats.to_string()
+    }
+}
+
+impl Default for Simulation {
+    fn default() -> Self {
+        Self::new()
     }
 }
@@ -8,6 +8,13 @@ pub struct Animal {
     pub(crate) eye: Eye,
     pub(crate) brain: Brain,
     pub(crate) food_eaten: usize,
+    pub(crate) distance_travelled: f32,
+    // Sum of the (normalized) speed and rotation adjustments made by the brain.
+    pub(crate) energy_spent: f32,
+    pub(crate) behaviour: Behaviour,
+    // Strategy parameters inherited through the chromosome; the brain doesn't
+    // use them, but they're passed on to the next generation.
+    pub(crate) strategy: Vec<f32>,
 }
 
 impl Animal {
@@ -15,17 +22,10 @@ impl Animal {
         let eye = Eye::default();
         let brain = Brain::random(rng, eye.cells());
 
-        Self {
-            position: rng.gen(),
-            rotation: rng.gen(),
-            speed: 0.002,
-            eye,
-            brain,
-            food_eaten: 0,
-        }
+        Self::new(eye, brain, Vec::new(), rng)
     }
 
-    fn new(eye: Eye, brain: Brain, rng: &mut dyn RngCore) -> Self {
+    fn new(eye: Eye, brain: Brain, strategy: Vec<f32>, rng: &mut dyn RngCore) -> Self {
         Self {
             position: rng.gen(),
             rotation: rng.gen(),
@@ -33,6 +33,10 @@ impl Animal {
             eye,
             brain,
             food_eaten: 0,
+            distance_travelled: 0.0,
+            energy_spent: 0.0,
+            behaviour: Behaviour::new(),
+            strategy,
         }
     }
 
@@ -44,14 +48,21 @@ impl Animal {
         self.rotation
     }
 
+    pub fn behaviour(&self) -> &Behaviour {
+        &self.behaviour
+    }
+
     pub(crate) fn as_chromosome(&self) -> ga::Chromosome {
-        self.brain.as_chromosome()
+        self.brain
+            .as_chromosome()
+            .with_strategy(self.strategy.clone())
     }
 
     pub(crate) fn from_chromosome(chromosome: ga::Chromosome, rng: &mut dyn RngCore) -> Self {
         let eye = Eye::default();
+        let strategy = chromosome.strategy.clone();
         let brain = Brain::from_chromosome(eye.cells, chromosome);
 
-        Self::new(eye, brain, rng)
+        Self::new(eye, brain, strategy, rng)
     }
 }
@@ -3,17 +3,31 @@ use crate::*;
 #[derive(Debug)]
 pub struct AnimalIndividual {
     chromosome: ga::Chromosome,
-    fitness: f32,
+    food_eaten: f32,
+    // When set, evolution selects for this rather than for food eaten.
+    novelty: Option<f32>,
+    distance_travelled: f32,
+    energy_spent: f32,
 }
 
 impl AnimalIndividual {
     pub fn from_animal(animal: &Animal) -> Self {
         Self {
-            fitness: animal.food_eaten as f32,
+            food_eaten: animal.food_eaten as f32,
+            novelty: None,
+            distance_travelled: animal.distance_travelled,
+            energy_spent: animal.energy_spent,
             chromosome: animal.as_chromosome(),
         }
     }
 
+    // Makes the animal's novelty its fitness; its food eaten is still
+    // reported as the first objective.
+    pub fn with_novelty(mut self, novelty: f32) -> Self {
+        self.novelty = Some(novelty);
+        self
+    }
+
     pub fn to_animal(self, rng: &mut dyn RngCore) -> Animal {
         Animal::from_chromosome(self.chromosome, rng)
     }
@@ -22,16 +36,49 @@ impl AnimalIndividual {
 impl ga::Individual for AnimalIndividual {
     fn from_chromosome(chromosome: ga::Chromosome) -> Self {
         Self {
-            fitness: 0.0,
+            food_eaten: 0.0,
+            novelty: None,
+            distance_travelled: 0.0,
+            energy_spent: 0.0,
             chromosome,
         }
     }
 
     fn fitness(&self) -> f32 {
-        self.fitness
+        self.novelty.unwrap_or(self.food_eaten)
     }
 
     fn to_chromosome(&self) -> &ga::Chromosome {
         &self.chromosome
     }
 }
+
+// Food eaten vs. distance travelled vs. energy spent (negated, as every
+// objective is maximized).
+impl ga::MultiObjectiveIndividual for AnimalIndividual {
+    fn objectives(&self) -> Vec<f32> {
+        vec![self.food_eaten, self.distance_travelled, -self.energy_spent]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ga::{Individual, MultiObjectiveIndividual};
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_with_novelty() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut animal = Animal::random(&mut rng);
+        animal.food_eaten = 3;
+
+        let individual = AnimalIndividual::from_animal(&animal);
+        assert_eq!(individual.fitness(), 3.0);
+
+        let individual = individual.with_novelty(0.5);
+        assert_eq!(individual.fitness(), 0.5);
+        assert_eq!(individual.objectives()[0], 3.0);
+    }
+}
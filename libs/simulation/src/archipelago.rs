@@ -0,0 +1,104 @@
+use crate::*;
+
+// Several worlds evolving side by side, each one an island of a
+// `ga::IslandModel`, with the fittest animals migrating between them; this
+// brings more diversity without growing any single world.
+pub struct Archipelago<S, C, M> {
+    worlds: Vec<World>,
+    island_model: ga::IslandModel<S, C, M>,
+    age: usize,
+}
+
+impl<S, C, M> Archipelago<S, C, M>
+where
+    S: ga::SelectionMethod,
+    C: ga::CrossoverMethod,
+    M: ga::MutationMethod,
+{
+    // Creates a random world for every island of `island_model`.
+    pub fn random(rng: &mut dyn RngCore, island_model: ga::IslandModel<S, C, M>) -> Self {
+        let worlds = island_model
+            .islands()
+            .iter()
+            .map(|_| World::random(rng))
+            .collect();
+
+        Self {
+            worlds,
+            island_model,
+            age: 0,
+        }
+    }
+
+    pub fn worlds(&self) -> &[World] {
+        &self.worlds
+    }
+
+    // Returns the statistics of every world once a generation ends.
+    pub fn step(&mut self, rng: &mut dyn RngCore) -> Option<Vec<ga::Statistics>> {
+        for world in &mut self.worlds {
+            world.step(rng);
+        }
+
+        self.age += 1;
+
+        if self.age > GENERATION_LENGTH {
+            self.age = 0;
+            Some(self.evolve(rng))
+        } else {
+            None
+        }
+    }
+
+    pub fn train(&mut self, rng: &mut dyn RngCore) -> Vec<ga::Statistics> {
+        loop {
+            if let Some(stats) = self.step(rng) {
+                return stats;
+            }
+        }
+    }
+
+    fn evolve(&mut self, rng: &mut dyn RngCore) -> Vec<ga::Statistics> {
+        let populations: Vec<_> = self.worlds.iter().map(World::individuals).collect();
+        let (offspring, stats) = self.island_model.evolve(rng, &populations);
+
+        for (world, population) in self.worlds.iter_mut().zip(offspring) {
+            world.repopulate(rng, population);
+        }
+
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test_evolve() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let island = || {
+            ga::GeneticAlgorithm::new(
+                ga::TournamentSelection::new(2, 0.75),
+                ga::UniformCrossover::new(),
+                ga::UniformMutation::new(0.01, 0.3),
+            )
+        };
+
+        let island_model =
+            ga::IslandModel::new(vec![island(), island()], ga::MigrationTopology::Ring, 1, 2);
+
+        let mut archipelago = Archipelago::random(&mut rng, island_model);
+        let stats = archipelago.evolve(&mut rng);
+
+        assert_eq!(stats.len(), 2);
+        assert_eq!(archipelago.worlds().len(), 2);
+
+        for (world, stats) in archipelago.worlds().iter().zip(&stats) {
+            assert_eq!(world.animals().len(), stats.population_size());
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use crate::*;
+
+// Side of the grid the world is split into when tracking where an animal has
+// been.
+const GRID_SIZE: usize = 4;
+
+// What an animal did during its lifetime, regardless of how much food it got;
+// used by novelty search to tell different strategies apart.
+#[derive(Clone, Debug)]
+pub struct Behaviour {
+    // Number of steps spent in each grid cell, row by row.
+    visits: Vec<usize>,
+    // Sum of the (signed and absolute) rotation adjustments made by the brain.
+    turning: f32,
+    abs_turning: f32,
+    final_position: na::Point2<f32>,
+}
+
+impl Behaviour {
+    pub(crate) fn new() -> Self {
+        Self {
+            visits: vec![0; GRID_SIZE * GRID_SIZE],
+            turning: 0.0,
+            abs_turning: 0.0,
+            final_position: na::Point2::new(0.5, 0.5),
+        }
+    }
+
+    pub(crate) fn record_position(&mut self, position: na::Point2<f32>) {
+        let cell = |coord: f32| ((coord * GRID_SIZE as f32) as usize).min(GRID_SIZE - 1);
+
+        self.visits[cell(position.y) * GRID_SIZE + cell(position.x)] += 1;
+        self.final_position = position;
+    }
+
+    pub(crate) fn record_turn(&mut self, delta_rotation: f32) {
+        self.turning += delta_rotation;
+        self.abs_turning += delta_rotation.abs();
+    }
+
+    // Fraction of the grid cells visited at least once.
+    pub fn coverage(&self) -> f32 {
+        let visited = self.visits.iter().filter(|&&visits| visits > 0).count();
+
+        visited as f32 / self.visits.len() as f32
+    }
+
+    // Fixed-size summary of the behaviour, with every component in roughly
+    // [-1, 1]: the share of time spent in each grid cell, the coverage, the
+    // average (signed and absolute) turn per step, and the final position.
+    pub fn descriptor(&self) -> Vec<f32> {
+        let steps = self.visits.iter().sum::<usize>().max(1) as f32;
+
+        let mut descriptor: Vec<f32> = self
+            .visits
+            .iter()
+            .map(|&visits| visits as f32 / steps)
+            .collect();
+
+        descriptor.extend([
+            self.coverage(),
+            self.turning / steps / ROTATION_ACCEL,
+            self.abs_turning / steps / ROTATION_ACCEL,
+            self.final_position.x,
+            self.final_position.y,
+        ]);
+
+        descriptor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_descriptor() {
+        let mut behaviour = Behaviour::new();
+
+        behaviour.record_position(na::Point2::new(0.1, 0.1));
+        behaviour.record_turn(ROTATION_ACCEL);
+        behaviour.record_position(na::Point2::new(0.1, 0.2));
+        behaviour.record_turn(-ROTATION_ACCEL);
+        behaviour.record_position(na::Point2::new(0.9, 1.0));
+
+        let mut expected = vec![0.0; 21];
+        expected[0] = 2.0 / 3.0;
+        expected[15] = 1.0 / 3.0;
+        expected[16] = 2.0 / 16.0;
+        expected[17] = 0.0;
+        expected[18] = 2.0 / 3.0;
+        expected[19] = 0.9;
+        expected[20] = 1.0;
+
+        let actual = behaviour.descriptor();
+
+        assert_relative_eq!(actual.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn test_descriptor_without_steps() {
+        let descriptor = Behaviour::new().descriptor();
+
+        assert_eq!(descriptor.len(), 21);
+        assert!(descriptor.iter().all(|value| value.is_finite()));
+    }
+}
@@ -30,6 +30,12 @@ impl Brain {
         self.nn.propogate(inputs)
     }
 
+    // Number of genes (bias followed by incoming weights) of every neuron, in
+    // the order they appear in the brain's chromosome.
+    pub fn neuron_sizes(input_size: usize) -> Vec<usize> {
+        nn::Network::neuron_sizes(&Self::topology(input_size))
+    }
+
     fn topology(input_size: usize) -> [usize; 3] {
         [input_size, 2 * input_size, 2]
     }
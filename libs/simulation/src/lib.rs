@@ -1,13 +1,20 @@
-pub use self::{animal::*, animal_individual::*, brain::*, eye::*, food::*, world::*};
+pub use self::{
+    animal::*, animal_individual::*, archipelago::*, behaviour::*, brain::*, eye::*, food::*,
+    neuron_crossover::*, novelty::*, world::*,
+};
 
 mod animal;
 mod animal_individual;
+mod archipelago;
+mod behaviour;
 mod brain;
 mod eye;
 mod food;
+mod neuron_crossover;
+mod novelty;
 mod world;
 
-use lib_genetic_algorithm as ga;
+pub use lib_genetic_algorithm as ga;
 use nalgebra as na;
 use rand::{Rng, RngCore};
 use std::f32::consts::FRAC_PI_2;
@@ -17,14 +24,15 @@ const MAX_SPEED: f32 = 0.003;
 const SPEED_ACCEL: f32 = 0.02;
 const ROTATION_ACCEL: f32 = FRAC_PI_2;
 const GENERATION_LENGTH: usize = 2500;
+const HALL_OF_FAME_SIZE: usize = 10;
 
 pub struct Simulation {
     world: World,
-    ga: ga::GeneticAlgorithm<
-        ga::RouletteWheelSelection,
-        ga::UniformCrossover,
-        ga::GaussianMutation,
-    >,
+    optimizer: Box<dyn ga::Optimizer<AnimalIndividual>>,
+    lineage: Option<ga::Lineage>,
+    hall_of_fame: ga::HallOfFame,
+    novelty_search: Option<NoveltySearch>,
+    pareto_fronts: Option<Vec<Vec<Vec<f32>>>>,
     age: usize,
 }
 
@@ -32,23 +40,138 @@ impl Simulation {
     pub fn random(rng: &mut dyn RngCore) -> Self {
         Self {
             world: World::random(rng),
-            ga: ga::GeneticAlgorithm::new(
+            optimizer: Box::new(ga::GeneticAlgorithm::new(
                 ga::RouletteWheelSelection::new(),
                 ga::UniformCrossover::new(),
-                ga::GaussianMutation::new(0.01, 0.3),
-            ),
+                ga::UniformMutation::new(0.01, 0.3),
+            )),
+            lineage: None,
+            hall_of_fame: ga::HallOfFame::new(HALL_OF_FAME_SIZE),
+            novelty_search: None,
+            pareto_fronts: None,
             age: 0,
         }
     }
 
+    // Replaces the default genetic algorithm, e.g. with
+    // `ga::DifferentialEvolution`; this stops tracking the lineage, which only
+    // genetic algorithms report.
+    pub fn with_optimizer(
+        mut self,
+        optimizer: impl ga::Optimizer<AnimalIndividual> + 'static,
+    ) -> Self {
+        self.optimizer = Box::new(optimizer);
+        self.lineage = None;
+        self.pareto_fronts = None;
+        self
+    }
+
+    // Tracks the ancestry of every animal bred from now on by the current
+    // genetic algorithm (and by the ones `set_operators()` switches to). The
+    // lineage grows by a node per animal every generation, so it's off by
+    // default.
+    //
+    // Panics if the current optimizer isn't a genetic algorithm.
+    pub fn with_lineage(mut self) -> Self {
+        if self.lineage.is_some() {
+            return self;
+        }
+
+        let lineage = ga::Lineage::new();
+
+        assert!(
+            self.optimizer.add_observer(Box::new(lineage.clone())),
+            "only genetic algorithms report the lineage"
+        );
+
+        self.lineage = Some(lineage);
+        self
+    }
+
+    // Evolves animals for food eaten, distance travelled and energy saved all
+    // at once (see `AnimalIndividual::objectives()`) rather than for food
+    // alone, keeping track of the Pareto fronts; like `with_optimizer()`, this
+    // stops tracking the lineage.
+    pub fn with_nsga2<C, M>(mut self, nsga2: ga::Nsga2<C, M>) -> Self
+    where
+        C: ga::CrossoverMethod + 'static,
+        M: ga::MutationMethod + 'static,
+    {
+        self.optimizer = Box::new(nsga2);
+        self.lineage = None;
+        self.pareto_fronts = Some(Vec::new());
+        self
+    }
+
+    // Objectives of the animals NSGA-II kept to breed the current generation
+    // from, grouped into Pareto fronts (best first); `None` unless evolving
+    // `with_nsga2()`.
+    pub fn pareto_fronts(&self) -> Option<&[Vec<Vec<f32>>]> {
+        self.pareto_fronts.as_deref()
+    }
+
+    // Evolves animals for the novelty of their behaviour rather than for the
+    // food they eat; the returned statistics then describe novelty, while the
+    // hall of fame still ranks by food eaten.
+    pub fn with_novelty_search(mut self, novelty_search: NoveltySearch) -> Self {
+        self.novelty_search = Some(novelty_search);
+        self
+    }
+
+    pub fn novelty_search(&self) -> Option<&NoveltySearch> {
+        self.novelty_search.as_ref()
+    }
+
+    // Switches to a genetic algorithm with the given operators, e.g. ones
+    // picked in the UI; takes effect from the next generation on. Invalid
+    // operator parameters leave the current optimizer in place.
+    pub fn set_operators(
+        &mut self,
+        selection: &ga::SelectionConfig,
+        crossover: &ga::CrossoverConfig,
+        mutation: &ga::MutationConfig,
+    ) -> Result<(), ga::Error> {
+        let mut optimizer = ga::DynGeneticAlgorithm::from_config(selection, crossover, mutation)?;
+
+        if let Some(lineage) = &self.lineage {
+            optimizer = optimizer.with_observer(lineage.clone());
+        }
+
+        self.optimizer = Box::new(optimizer);
+        self.pareto_fronts = None;
+
+        Ok(())
+    }
+
     pub fn world(&self) -> &World {
         &self.world
     }
 
+    // Ancestry of every animal bred so far, if tracked `with_lineage()`;
+    // `world().animals[i]` corresponds to `lineage().current_ids()[i]` once the
+    // first generation has evolved.
+    pub fn lineage(&self) -> Option<&ga::Lineage> {
+        self.lineage.as_ref()
+    }
+
+    // Best brains of all time, judged by the food eaten during their lifetime.
+    pub fn hall_of_fame(&self) -> &ga::HallOfFame {
+        &self.hall_of_fame
+    }
+
+    // Chromosome of the best brain of all time, e.g. to save it or to seed
+    // another simulation's population with.
+    pub fn champion(&self) -> Option<&ga::Chromosome> {
+        self.hall_of_fame.best().map(|(chromosome, _)| chromosome)
+    }
+
+    // Genetic diversity of the current generation's brains.
+    pub fn diversity(&self) -> ga::DiversityStatistics {
+        ga::DiversityStatistics::new(&self.world.individuals())
+    }
+
     pub fn step(&mut self, rng: &mut dyn RngCore) -> Option<ga::Statistics> {
-        self.process_collisions(rng);
-        self.process_brains();
-        self.process_movement();
+        self.world.step(rng);
 
         self.age += 1;
 
@@ -60,72 +183,158 @@ impl Simulation {
         }
     }
 
-    fn process_collisions(&mut self, rng: &mut dyn RngCore) {
-        for animal in &mut self.world.animals {
-            for food in &mut self.world.foods {
-                let distance = na::distance(&animal.position, &food.position);
-
-                if distance <= 0.01 {
-                    food.position = rng.gen();
-                    animal.food_eaten += 1;
-                }
+    pub fn train(&mut self, rng: &mut dyn RngCore) -> ga::Statistics {
+        loop {
+            if let Some(stats) = self.step(rng) {
+                return stats;
             }
         }
     }
 
-    fn process_brains(&mut self) {
-        for animal in &mut self.world.animals {
-            let vision =
-                animal
-                    .eye
-                    .process_vision(animal.position, animal.rotation, &self.world.foods);
+    // Trains generation after generation until `controller` says to stop.
+    pub fn run(&mut self, rng: &mut dyn RngCore, controller: &ga::RunController) -> ga::RunSummary {
+        controller.run(|| self.train(rng))
+    }
+
+    fn evolve(&mut self, rng: &mut dyn RngCore) -> ga::Statistics {
+        let animal_individuals = self.world.individuals();
 
-            let adjustment = animal.brain.propogate(vision);
+        self.hall_of_fame.update(&animal_individuals);
 
-            let delta_speed = adjustment[0].clamp(-SPEED_ACCEL, SPEED_ACCEL);
-            let delta_rotation = adjustment[1].clamp(-ROTATION_ACCEL, ROTATION_ACCEL);
+        let animal_individuals = match &mut self.novelty_search {
+            Some(novelty_search) => {
+                let descriptors: Vec<_> = self
+                    .world
+                    .animals
+                    .iter()
+                    .map(|animal| animal.behaviour.descriptor())
+                    .collect();
 
-            animal.speed = (animal.speed + delta_speed).clamp(MIN_SPEED, MAX_SPEED);
-            animal.rotation = na::Rotation2::new(animal.rotation.angle() + delta_rotation);
+                animal_individuals
+                    .into_iter()
+                    .zip(novelty_search.evaluate(&descriptors))
+                    .map(|(individual, novelty)| individual.with_novelty(novelty))
+                    .collect()
+            }
+            None => animal_individuals,
+        };
+
+        let (new_population, stats) = self.optimizer.evolve(rng, &animal_individuals);
+
+        if self.pareto_fronts.is_some() {
+            self.pareto_fronts = self.optimizer.pareto_fronts();
         }
+
+        self.world.repopulate(rng, new_population);
+
+        stats
     }
+}
 
-    fn process_movement(&mut self) {
-        for animal in &mut self.world.animals {
-            animal.position += animal.rotation * na::Vector2::new(0.0, animal.speed);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
 
-            animal.position.x = na::wrap(animal.position.x, 0.0, 1.0);
-            animal.position.y = na::wrap(animal.position.y, 0.0, 1.0);
-        }
+    #[test]
+    fn test_nsga2() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let mut simulation =
+            Simulation::random(&mut rng)
+                .with_lineage()
+                .with_nsga2(ga::Nsga2::new(
+                    ga::UniformCrossover::new(),
+                    ga::UniformMutation::new(0.01, 0.3),
+                ));
+
+        assert!(simulation.lineage().is_none());
+        assert_eq!(simulation.pareto_fronts(), Some(&[][..]));
+
+        simulation.evolve(&mut rng);
+
+        let fronts = simulation.pareto_fronts().unwrap();
+        let animals: usize = fronts.iter().map(|front| front.len()).sum();
+
+        assert_eq!(animals, simulation.world().animals().len());
+        assert!(fronts
+            .iter()
+            .flatten()
+            .all(|objectives| objectives.len() == 3));
     }
 
-    pub fn train(&mut self, rng: &mut dyn RngCore) -> ga::Statistics {
-        loop {
-            if let Some(stats) = self.step(rng) {
-                return stats;
-            }
+    #[test]
+    fn test_lineage() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        assert!(Simulation::random(&mut rng).lineage().is_none());
+
+        let mut simulation = Simulation::random(&mut rng).with_lineage();
+
+        // roulette wheel selection needs someone to have eaten
+        for (food_eaten, animal) in simulation.world.animals.iter_mut().enumerate() {
+            animal.food_eaten = food_eaten;
         }
+
+        simulation.evolve(&mut rng);
+
+        let lineage = simulation.lineage().unwrap();
+        let animals = simulation.world().animals().len();
+
+        // the initial animals and their children
+        assert_eq!(lineage.len(), 2 * animals);
+        assert_eq!(lineage.current_ids().len(), animals);
     }
 
-    fn evolve(&mut self, rng: &mut dyn RngCore) -> ga::Statistics {
-        let animal_individuals: Vec<_> = self
-            .world
-            .animals
-            .iter()
-            .map(AnimalIndividual::from_animal)
-            .collect();
+    #[test]
+    fn test_lineage_keeps_optimizer() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
 
-        let (new_population, stats) = self.ga.evolve(rng, &animal_individuals);
+        // unlike the default roulette wheel selection, tournaments don't need
+        // anyone to have eaten
+        let mut simulation = Simulation::random(&mut rng)
+            .with_optimizer(ga::GeneticAlgorithm::new(
+                ga::TournamentSelection::new(2, 1.0),
+                ga::UniformCrossover::new(),
+                ga::UniformMutation::new(0.01, 0.3),
+            ))
+            .with_lineage();
 
-        self.world.animals = new_population
-            .into_iter()
-            .map(|individual| individual.to_animal(rng))
-            .collect();
+        simulation.evolve(&mut rng);
 
-        for food in &mut self.world.foods {
-            food.position = rng.gen();
+        let animals = simulation.world().animals().len();
+
+        assert_eq!(simulation.lineage().unwrap().len(), 2 * animals);
+    }
+
+    #[test]
+    #[should_panic(expected = "only genetic algorithms report the lineage")]
+    fn test_lineage_needs_genetic_algorithm() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+
+        let _ = Simulation::random(&mut rng)
+            .with_nsga2(ga::Nsga2::new(
+                ga::UniformCrossover::new(),
+                ga::UniformMutation::new(0.01, 0.3),
+            ))
+            .with_lineage();
+    }
+
+    #[test]
+    fn test_champion() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let mut simulation = Simulation::random(&mut rng);
+
+        assert!(simulation.champion().is_none());
+
+        for (food_eaten, animal) in simulation.world.animals.iter_mut().enumerate() {
+            animal.food_eaten = food_eaten;
         }
 
-        stats
+        let best = simulation.world.animals.last().unwrap().as_chromosome();
+        simulation.evolve(&mut rng);
+
+        assert_eq!(simulation.champion().unwrap().genes(), best.genes());
     }
 }
@@ -0,0 +1,83 @@
+use crate::*;
+
+// Crossover that swaps whole neurons (bias plus incoming weights) between the
+// parents' brains, instead of individual genes, so that features a parent has
+// already learned are passed on intact.
+#[derive(Clone, Debug)]
+pub struct NeuronCrossover {
+    neuron_sizes: Vec<usize>,
+}
+
+impl NeuronCrossover {
+    pub fn new(neuron_sizes: Vec<usize>) -> Self {
+        assert!(neuron_sizes.iter().all(|&size| size > 0));
+
+        Self { neuron_sizes }
+    }
+
+    pub fn for_eye(eye: &Eye) -> Self {
+        Self::new(Brain::neuron_sizes(eye.cells()))
+    }
+}
+
+impl ga::CrossoverMethod for NeuronCrossover {
+    fn crossover(
+        &self,
+        rng: &mut dyn RngCore,
+        parent_a: &ga::Chromosome,
+        parent_b: &ga::Chromosome,
+    ) -> ga::Chromosome {
+        assert_eq!(parent_a.len(), self.neuron_sizes.iter().sum::<usize>());
+        assert_eq!(parent_a.len(), parent_b.len());
+
+        let mut child = Vec::with_capacity(parent_a.len());
+        let mut start = 0;
+
+        for size in &self.neuron_sizes {
+            let parent = if rng.gen_bool(0.5) {
+                parent_a
+            } else {
+                parent_b
+            };
+
+            child.extend_from_slice(&parent.genes()[start..start + size]);
+            start += size;
+        }
+
+        ga::Chromosome::new(child)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ga::CrossoverMethod;
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    #[test]
+    fn test() {
+        let mut rng = ChaCha8Rng::from_seed(Default::default());
+        let crossover = NeuronCrossover::for_eye(&Eye::default());
+        let gene_cnt: usize = crossover.neuron_sizes.iter().sum();
+
+        let parent_a: ga::Chromosome = (1..=gene_cnt).map(|n| n as f32).collect();
+        let parent_b: ga::Chromosome = (1..=gene_cnt).map(|n| -(n as f32)).collect();
+
+        let child = crossover.crossover(&mut rng, &parent_a, &parent_b);
+
+        let mut start = 0;
+        let mut from_a = Vec::new();
+        for size in &crossover.neuron_sizes {
+            let neuron = &child.genes()[start..start + size];
+
+            assert!(neuron.iter().all(|gene| *gene > 0.0) || neuron.iter().all(|gene| *gene < 0.0));
+
+            from_a.push(neuron[0] > 0.0);
+            start += size;
+        }
+
+        assert_eq!(from_a.len(), 20);
+        assert_eq!(from_a.iter().filter(|from_a| **from_a).count(), 9);
+    }
+}
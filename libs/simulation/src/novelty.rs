@@ -0,0 +1,119 @@
+use crate::*;
+
+// Novelty search: rather than rewarding food eaten, rewards animals for
+// behaving differently from their peers and from the notable behaviours seen
+// in previous generations, which helps escape deceptive local optima.
+#[derive(Clone, Debug)]
+pub struct NoveltySearch {
+    // Number of nearest neighbours the novelty is averaged over.
+    k: usize,
+    // Behaviours at least this novel get archived.
+    archive_threshold: f32,
+    // Once full, the oldest archived behaviours make room for new ones.
+    archive_capacity: usize,
+    archive: Vec<Vec<f32>>,
+}
+
+impl NoveltySearch {
+    pub fn new(k: usize, archive_threshold: f32) -> Self {
+        assert!(k > 0);
+
+        Self {
+            k,
+            archive_threshold,
+            archive_capacity: 1000,
+            archive: Vec::new(),
+        }
+    }
+
+    pub fn with_archive_capacity(mut self, archive_capacity: usize) -> Self {
+        self.archive_capacity = archive_capacity;
+        self
+    }
+
+    pub fn archive(&self) -> &[Vec<f32>] {
+        &self.archive
+    }
+
+    // Returns the novelty of each behaviour descriptor - its mean distance to
+    // the `k` nearest other descriptors and archived ones - then archives the
+    // sufficiently novel ones.
+    pub fn evaluate(&mut self, descriptors: &[Vec<f32>]) -> Vec<f32> {
+        let novelty: Vec<f32> = descriptors
+            .iter()
+            .enumerate()
+            .map(|(i, descriptor)| {
+                let mut distances: Vec<f32> = descriptors
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, other)| other)
+                    .chain(&self.archive)
+                    .map(|other| ga::distance(descriptor, other))
+                    .collect();
+
+                if distances.is_empty() {
+                    return 0.0;
+                }
+
+                distances.sort_by(f32::total_cmp);
+                distances.truncate(self.k);
+
+                distances.iter().sum::<f32>() / distances.len() as f32
+            })
+            .collect();
+
+        for (descriptor, &novelty) in descriptors.iter().zip(&novelty) {
+            if novelty >= self.archive_threshold {
+                self.archive.push(descriptor.clone());
+            }
+        }
+
+        let overflow = self.archive.len().saturating_sub(self.archive_capacity);
+        self.archive.drain(..overflow);
+
+        novelty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_evaluate() {
+        let mut novelty_search = NoveltySearch::new(1, 1.5);
+
+        let novelty = novelty_search.evaluate(&[vec![0.0], vec![1.0], vec![3.0]]);
+
+        assert_relative_eq!(novelty.as_slice(), [1.0, 1.0, 2.0].as_slice());
+        assert_eq!(novelty_search.archive(), &[vec![3.0]]);
+
+        // The archived behaviour is no longer novel.
+        let novelty = novelty_search.evaluate(&[vec![3.0], vec![-3.0]]);
+
+        assert_relative_eq!(novelty.as_slice(), [0.0, 6.0].as_slice());
+        assert_eq!(novelty_search.archive(), &[vec![3.0], vec![-3.0]]);
+    }
+
+    #[test]
+    fn test_evaluate_averages_k_nearest() {
+        let mut novelty_search = NoveltySearch::new(2, f32::INFINITY);
+
+        let novelty = novelty_search.evaluate(&[vec![0.0], vec![1.0], vec![3.0]]);
+
+        assert_relative_eq!(novelty.as_slice(), [2.0, 1.5, 2.5].as_slice());
+        assert!(novelty_search.archive().is_empty());
+    }
+
+    #[test]
+    fn test_archive_capacity() {
+        let mut novelty_search = NoveltySearch::new(1, 0.0).with_archive_capacity(2);
+
+        novelty_search.evaluate(&[vec![0.0], vec![1.0]]);
+        novelty_search.evaluate(&[vec![5.0]]);
+
+        assert_eq!(novelty_search.archive(), &[vec![1.0], vec![5.0]]);
+    }
+}
@@ -1,4 +1,6 @@
 use crate::*;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 
 #[derive(Debug)]
 pub struct World {
@@ -21,4 +23,90 @@ impl World {
     pub fn foods(&self) -> &[Food] {
         &self.foods
     }
+
+    // The animals as individuals to evolve, rated by the food they've eaten.
+    pub(crate) fn individuals(&self) -> Vec<AnimalIndividual> {
+        self.animals
+            .iter()
+            .map(AnimalIndividual::from_animal)
+            .collect()
+    }
+
+    // Replaces the animals with the next generation and scatters the food
+    // anew.
+    pub(crate) fn repopulate(&mut self, rng: &mut dyn RngCore, population: Vec<AnimalIndividual>) {
+        self.animals = population
+            .into_iter()
+            .map(|individual| individual.to_animal(rng))
+            .collect();
+
+        for food in &mut self.foods {
+            food.position = rng.gen();
+        }
+    }
+
+    // Moves every animal by one step, letting them eat the food they bump
+    // into along the way.
+    pub(crate) fn step(&mut self, rng: &mut dyn RngCore) {
+        self.process_collisions(rng);
+        self.process_brains();
+        self.process_movement();
+    }
+
+    fn process_collisions(&mut self, rng: &mut dyn RngCore) {
+        for animal in &mut self.animals {
+            for food in &mut self.foods {
+                let distance = na::distance(&animal.position, &food.position);
+
+                if distance <= 0.01 {
+                    food.position = rng.gen();
+                    animal.food_eaten += 1;
+                }
+            }
+        }
+    }
+
+    // The only part of the simulation the `rayon` feature parallelizes.
+    fn process_brains(&mut self) {
+        let foods = &self.foods;
+
+        #[cfg(not(feature = "rayon"))]
+        self.animals
+            .iter_mut()
+            .for_each(|animal| Self::process_brain(animal, foods));
+
+        #[cfg(feature = "rayon")]
+        self.animals
+            .par_iter_mut()
+            .for_each(|animal| Self::process_brain(animal, foods));
+    }
+
+    fn process_brain(animal: &mut Animal, foods: &[Food]) {
+        let vision = animal
+            .eye
+            .process_vision(animal.position, animal.rotation, foods);
+
+        let adjustment = animal.brain.propogate(vision);
+
+        let delta_speed = adjustment[0].clamp(-SPEED_ACCEL, SPEED_ACCEL);
+        let delta_rotation = adjustment[1].clamp(-ROTATION_ACCEL, ROTATION_ACCEL);
+
+        animal.speed = (animal.speed + delta_speed).clamp(MIN_SPEED, MAX_SPEED);
+        animal.rotation = na::Rotation2::new(animal.rotation.angle() + delta_rotation);
+        animal.behaviour.record_turn(delta_rotation);
+        animal.energy_spent +=
+            delta_speed.abs() / SPEED_ACCEL + delta_rotation.abs() / ROTATION_ACCEL;
+    }
+
+    fn process_movement(&mut self) {
+        for animal in &mut self.animals {
+            animal.position += animal.rotation * na::Vector2::new(0.0, animal.speed);
+            animal.distance_travelled += animal.speed;
+
+            animal.position.x = na::wrap(animal.position.x, 0.0, 1.0);
+            animal.position.y = na::wrap(animal.position.y, 0.0, 1.0);
+
+            animal.behaviour.record_position(animal.position);
+        }
+    }
 }